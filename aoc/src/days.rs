@@ -0,0 +1,52 @@
+use common::bench::{bench, Bench};
+use common::solution::solve;
+use common::Report;
+
+/// Entry points of a solved day
+#[derive(Copy, Clone)]
+pub struct Day {
+    /// Solves the day from its puzzle input
+    pub solve: fn(&str) -> anyhow::Result<Report>,
+    /// Times the phases of the day over the given number of runs
+    pub bench: fn(&str, usize) -> anyhow::Result<Bench>,
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            solve: solve::<$solution>,
+            bench: bench::<$solution>,
+        }
+    };
+}
+
+/// All solved days in order
+pub const DAYS: [Day; 17] = [
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+    day!(day07::Day07),
+    day!(day08::Day08),
+    day!(day09::Day09),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+];
+
+/// Numbers of all solved days
+pub fn numbers() -> impl Iterator<Item = u8> {
+    1..=DAYS.len() as u8
+}
+
+/// Get the entry points of the given day
+pub fn get(day: u8) -> Option<Day> {
+    DAYS.get(usize::from(day).checked_sub(1)?).copied()
+}
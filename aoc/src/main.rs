@@ -0,0 +1,296 @@
+mod days;
+mod scaffold;
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use clap::Clap;
+
+use common::answers::{input_hash, Registry};
+use common::bench::Bench;
+use common::input::{self, Source};
+use common::inputs::InputCache;
+use common::{Format, Report};
+
+#[derive(Clap)]
+#[clap(
+    version = "1.0",
+    author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
+)]
+struct Opts {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Clap)]
+enum Command {
+    /// Run the solution of one or all days
+    Run(Run),
+    /// Download the inputs of one or all days into the input cache
+    Fetch(Fetch),
+    /// Store an example input copied from the puzzle text
+    Example(Example),
+    /// Generate the crate of a new day from the template
+    New(New),
+}
+
+#[derive(Clap)]
+struct Run {
+    /// Day to run (1-17) or "all"
+    day: DaySelection,
+
+    /// Path to input file, "-" reads from stdin. Only usable when running a single day
+    #[clap(short, long)]
+    input: Option<Source>,
+
+    /// Puzzle input given inline. Only usable when running a single day
+    #[clap(long, conflicts_with = "input")]
+    input_string: Option<String>,
+
+    /// Run on the stored example with this name. Only usable when running a single day
+    #[clap(long, value_name = "NAME", conflicts_with_all = &["input", "input-string"])]
+    example: Option<String>,
+
+    /// Input cache directory, defaults to $AOC_INPUT_DIR or inputs/ in the workspace
+    #[clap(long)]
+    input_dir: Option<PathBuf>,
+
+    /// Directory containing the dayXX directories with their answers.toml
+    #[clap(long, default_value = ".")]
+    root: PathBuf,
+
+    /// Output format: text, json or csv
+    #[clap(short, long, default_value = "text")]
+    format: Format,
+
+    /// Time parse, part 1 and part 2 over N runs instead of printing the answers
+    #[clap(long, value_name = "N")]
+    bench: Option<usize>,
+
+    /// Compare the answers with the ones stored in dayXX/answers.toml, fails on mismatch
+    #[clap(long, conflicts_with = "bench")]
+    check: bool,
+
+    /// Store answers that are not known yet in dayXX/answers.toml. Implies --check
+    #[clap(long, conflicts_with = "bench")]
+    record: bool,
+}
+
+#[derive(Clap)]
+struct Fetch {
+    /// Day to fetch (1-17) or "all"
+    day: DaySelection,
+
+    /// Input cache directory, defaults to $AOC_INPUT_DIR or inputs/ in the workspace
+    #[clap(long)]
+    input_dir: Option<PathBuf>,
+}
+
+#[derive(Clap)]
+struct Example {
+    /// Day the example belongs to
+    day: u8,
+
+    /// Name of the example, e.g. the number of the example in the puzzle text
+    name: String,
+
+    /// Path to the example, "-" reads from stdin
+    #[clap(short, long, default_value = "-")]
+    input: Source,
+
+    /// Input cache directory, defaults to $AOC_INPUT_DIR or inputs/ in the workspace
+    #[clap(long)]
+    input_dir: Option<PathBuf>,
+}
+
+#[derive(Clap)]
+struct New {
+    /// Day to generate the dayXX crate for
+    day: u8,
+
+    /// Workspace directory containing the README and the dayXX directories
+    #[clap(long, default_value = ".")]
+    root: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let options = Opts::parse();
+
+    match options.command {
+        Command::Run(run) => run_days(&run),
+        Command::Fetch(fetch) => fetch_days(&fetch),
+        Command::Example(example) => store_example(&example),
+        Command::New(new) => new_day(&new),
+    }
+}
+
+fn run_days(run: &Run) -> anyhow::Result<()> {
+    let cache = input_cache(&run.input_dir);
+
+    let inputs = match run.day {
+        DaySelection::All => {
+            if run.input.is_some() || run.input_string.is_some() || run.example.is_some() {
+                bail!("an input file can only be given when running a single day");
+            }
+
+            days::numbers()
+                .map(|day| Ok((day, cache.source(day)?)))
+                .collect::<anyhow::Result<Vec<_>>>()?
+        }
+        DaySelection::Day(day) => {
+            let input = match (&run.input, &run.input_string, &run.example) {
+                (_, Some(content), _) => Source::Inline(content.clone()),
+                (Some(source), None, _) => source.clone(),
+                (None, None, Some(name)) => Source::File(cache.example_path(day, name)?),
+                (None, None, None) => cache.source(day)?,
+            };
+
+            vec![(day, input)]
+        }
+    };
+
+    if let Some(runs) = run.bench {
+        let benches = inputs
+            .iter()
+            .map(|(day, input)| bench_day(*day, input, runs))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        run.format.print_bench(&benches)?;
+    } else if run.check || run.record {
+        let mut mismatches = 0;
+        for (day, input) in &inputs {
+            mismatches += check_day(*day, input, &run.root, run.record)?;
+        }
+
+        if mismatches > 0 {
+            bail!("{} answers do not match the known ones", mismatches);
+        }
+    } else {
+        let reports = inputs
+            .iter()
+            .map(|(day, input)| run_day(*day, &input::load(input)?))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        run.format.print(&reports)?;
+    }
+
+    Ok(())
+}
+
+fn run_day(day: u8, content: &str) -> anyhow::Result<Report> {
+    let entry = days::get(day).with_context(|| format!("day {} is not solved", day))?;
+
+    (entry.solve)(content).with_context(|| format!("failed to solve day {}", day))
+}
+
+/// Print the outcome of every part, returns the number of mismatches
+fn check_day(day: u8, input: &Source, root: &Path, record: bool) -> anyhow::Result<usize> {
+    let content = input::load(input)?;
+    let report = run_day(day, &content)?;
+
+    let path = answers_file(root, day);
+    let mut registry = Registry::load(&path)?;
+    let hash = input_hash(&content);
+
+    let checks = registry.check(&hash, &report);
+    for check in &checks {
+        println!("{}", check);
+    }
+
+    if record {
+        let recorded = registry.record(&hash, &report);
+        if recorded > 0 {
+            registry.save(&path)?;
+            println!("Recorded {} answers in {}", recorded, path.display());
+        }
+    }
+
+    Ok(checks.iter().filter(|check| check.is_mismatch()).count())
+}
+
+fn bench_day(day: u8, input: &Source, runs: usize) -> anyhow::Result<Bench> {
+    let entry = days::get(day).with_context(|| format!("day {} is not solved", day))?;
+
+    let content = input::load(input)?;
+
+    (entry.bench)(&content, runs).with_context(|| format!("failed to benchmark day {}", day))
+}
+
+fn fetch_days(fetch: &Fetch) -> anyhow::Result<()> {
+    let cache = input_cache(&fetch.input_dir);
+
+    let days = match fetch.day {
+        DaySelection::All => days::numbers().collect(),
+        DaySelection::Day(day) => vec![day],
+    };
+
+    for day in days {
+        let path = cache.ensure(day)?;
+        println!("Day {:02}: {}", day, path.display());
+    }
+
+    Ok(())
+}
+
+fn store_example(example: &Example) -> anyhow::Result<()> {
+    let cache = input_cache(&example.input_dir);
+
+    let content = input::load(&example.input)?;
+    let path = cache.store_example(example.day, &example.name, &content)?;
+    println!("Stored example in {}", path.display());
+
+    Ok(())
+}
+
+fn new_day(new: &New) -> anyhow::Result<()> {
+    if !(1..=25).contains(&new.day) {
+        bail!("there is no day {}", new.day);
+    }
+
+    let dir = scaffold::new_day(&new.root, new.day)?;
+    println!("Generated {}", dir.display());
+    println!(
+        "Add day{:02} to aoc/Cargo.toml and aoc/src/days.rs to run it with `aoc run`",
+        new.day
+    );
+
+    Ok(())
+}
+
+fn answers_file(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{:02}", day)).join("answers.toml")
+}
+
+fn input_cache(dir: &Option<PathBuf>) -> InputCache {
+    let cache = InputCache::from_env();
+    match dir {
+        Some(dir) => cache.with_dir(dir),
+        None => cache,
+    }
+}
+
+/// Which days to run
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        let day = s
+            .parse::<u8>()
+            .with_context(|| format!("expected a day or \"all\", got \"{}\"", s))?;
+
+        if days::get(day).is_none() {
+            bail!("day {} is not solved", day);
+        }
+
+        Ok(DaySelection::Day(day))
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+/// Template files of a day crate with their path in it
+const TEMPLATES: [(&str, &str); 4] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("README.md", include_str!("../templates/README.md.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+];
+
+/// Generate the dayXX crate in root and list it in the README, returns the crate directory
+pub fn new_day(root: &Path, day: u8) -> anyhow::Result<PathBuf> {
+    let dir = root.join(format!("day{:02}", day));
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    for (path, template) in TEMPLATES.iter() {
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        fs::write(&path, render(template, day))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    let readme = root.join("README.md");
+    let content = fs::read_to_string(&readme)
+        .with_context(|| format!("failed to read {}", readme.display()))?;
+    fs::write(&readme, add_to_days_list(&content, day))
+        .with_context(|| format!("failed to write {}", readme.display()))?;
+
+    Ok(dir)
+}
+
+/// Replace {{day}} with the zero padded and {{day_number}} with the plain day
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &format!("{:02}", day))
+        .replace("{{day_number}}", &day.to_string())
+}
+
+/// Insert the day into the "## Days" list, keeping it sorted
+fn add_to_days_list(readme: &str, day: u8) -> String {
+    let entry = format!("- [Day {:02}](day{:02})", day, day);
+    let mut lines = readme.lines().collect::<Vec<_>>();
+
+    if lines.contains(&entry.as_str()) {
+        return readme.to_owned();
+    }
+
+    let start = match lines.iter().position(|line| *line == "## Days") {
+        Some(heading) => heading + 1,
+        None => {
+            lines.push("");
+            lines.push("## Days");
+            lines.len()
+        }
+    };
+    let end = lines[start..]
+        .iter()
+        .position(|line| !line.starts_with("- "))
+        .map_or(lines.len(), |offset| start + offset);
+
+    // zero padded entries sort like their days
+    let index = lines[start..end]
+        .iter()
+        .position(|line| *line > entry.as_str())
+        .map_or(end, |offset| start + offset);
+    lines.insert(index, &entry);
+
+    let mut result = lines.join("\n");
+    if readme.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_list() {
+        let readme = "# AoC\n\n## Days\n- [Day 01](day01)\n- [Day 17](day17)\n\n## Common\n";
+
+        let added = add_to_days_list(readme, 18);
+        assert_eq!(
+            added,
+            "# AoC\n\n## Days\n- [Day 01](day01)\n- [Day 17](day17)\n- [Day 18](day18)\n\n## Common\n"
+        );
+        assert_eq!(add_to_days_list(&added, 18), added);
+        assert!(add_to_days_list(readme, 2).contains("- [Day 01](day01)\n- [Day 02](day02)\n"));
+    }
+
+    #[test]
+    fn render_day() {
+        assert_eq!(render("day{{day}}: {{day_number}}", 8), "day08: 8");
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::report::{Part, Report};
+use error::AnswersError;
+
+/// Hash identifying a puzzle input (64 bit FNV-1a as hex)
+///
+/// Stable across runs and platforms, so it can be stored in answer files
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+// Registry
+//##################
+
+/// Known answers of a day keyed by input hash and part
+///
+/// Stored as toml:
+/// ```toml
+/// [inputs.0123456789abcdef]
+/// 1 = "270144"
+/// 2 = "261342720"
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Registry {
+    #[serde(default)]
+    inputs: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Registry {
+    /// Load the registry, a missing file is an empty registry
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|source| AnswersError::Parse {
+                path: path.to_owned(),
+                source,
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Registry::default()),
+            Err(source) => Err(AnswersError::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let content = toml::to_string(self)?;
+        fs::write(path, content).map_err(|source| AnswersError::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn get(&self, hash: &str, part: &Part) -> Option<&str> {
+        self.inputs
+            .get(hash)?
+            .get(&part.to_string())
+            .map(String::as_str)
+    }
+
+    /// Store the answer, returns the previously known one
+    pub fn insert(&mut self, hash: &str, part: &Part, answer: String) -> Option<String> {
+        self.inputs
+            .entry(hash.to_owned())
+            .or_default()
+            .insert(part.to_string(), answer)
+    }
+
+    /// Compare all parts of the report with the known answers
+    pub fn check(&self, hash: &str, report: &Report) -> Vec<Check> {
+        report
+            .parts
+            .iter()
+            .map(|result| {
+                let outcome = match self.get(hash, &result.part) {
+                    Some(expected) if expected == result.answer => Outcome::Match,
+                    Some(expected) => Outcome::Mismatch(expected.to_owned()),
+                    None => Outcome::Unknown,
+                };
+
+                Check {
+                    day: report.day,
+                    part: result.part.clone(),
+                    answer: result.answer.clone(),
+                    outcome,
+                }
+            })
+            .collect()
+    }
+
+    /// Store all answers of the report that are not known yet
+    ///
+    /// Mismatching answers are kept, those need to be fixed by hand
+    pub fn record(&mut self, hash: &str, report: &Report) -> usize {
+        let mut recorded = 0;
+
+        for result in &report.parts {
+            if self.get(hash, &result.part).is_none() {
+                self.insert(hash, &result.part, result.answer.clone());
+                recorded += 1;
+            }
+        }
+
+        recorded
+    }
+}
+
+//##################
+
+// Check
+//##################
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Match,
+    /// Contains the expected answer
+    Mismatch(String),
+    /// No answer known for this input
+    Unknown,
+}
+
+/// Result of comparing a computed answer with the known one
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn is_mismatch(&self) -> bool {
+        matches!(self.outcome, Outcome::Mismatch(_))
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} part {}: ", self.day, self.part)?;
+        match &self.outcome {
+            Outcome::Match => write!(f, "ok ({})", self.answer),
+            Outcome::Mismatch(expected) => {
+                write!(f, "MISMATCH expected {}, got {}", expected, self.answer)
+            }
+            Outcome::Unknown => write!(f, "unknown ({})", self.answer),
+        }
+    }
+}
+
+//##################
+
+pub mod error {
+    use std::io;
+    use std::path::PathBuf;
+
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    pub enum AnswersError {
+        #[error("can not access answers file {}", path.display())]
+        Io {
+            path: PathBuf,
+            #[source]
+            source: io::Error,
+        },
+
+        #[error("malformed answers file {}", path.display())]
+        Parse {
+            path: PathBuf,
+            #[source]
+            source: toml::de::Error,
+        },
+
+        #[error("error serializing answers: {0}")]
+        Serialize(#[from] toml::ser::Error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::PartResult;
+    use std::time::Duration;
+
+    fn report(part1: &str, part2: &str) -> Report {
+        Report::new(1)
+            .with_part(PartResult::new(
+                Part::One,
+                &part1.to_owned(),
+                Duration::default(),
+            ))
+            .with_part(PartResult::new(
+                Part::Two,
+                &part2.to_owned(),
+                Duration::default(),
+            ))
+    }
+
+    #[test]
+    fn check_and_record() {
+        let hash = input_hash("1721\n979\n366");
+        let mut registry = Registry::default();
+        registry.insert(&hash, &Part::One, "514579".to_owned());
+
+        let checks = registry.check(&hash, &report("514579", "241861950"));
+        assert_eq!(checks[0].outcome, Outcome::Match);
+        assert_eq!(checks[1].outcome, Outcome::Unknown);
+
+        assert_eq!(registry.record(&hash, &report("1", "241861950")), 1);
+        assert_eq!(registry.get(&hash, &Part::One), Some("514579"));
+        assert_eq!(registry.get(&hash, &Part::Two), Some("241861950"));
+
+        let checks = registry.check(&hash, &report("1", "241861950"));
+        assert_eq!(checks[0].outcome, Outcome::Mismatch("514579".to_owned()));
+        assert!(checks[0].is_mismatch());
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut registry = Registry::default();
+        registry.insert(&input_hash("a"), &Part::One, "1".to_owned());
+        registry.insert(
+            &input_hash("a"),
+            &Part::Extra("5d".to_owned()),
+            "2".to_owned(),
+        );
+
+        let content = toml::to_string(&registry).unwrap();
+        assert_eq!(toml::from_str::<Registry>(&content).unwrap(), registry);
+    }
+
+    #[test]
+    fn stable_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+}
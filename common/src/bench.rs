@@ -0,0 +1,270 @@
+use std::fmt;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::report::{csv_field, Format, Part};
+use crate::solution::Solution;
+
+/// Time parse, part 1 and part 2 of a day separately over the given number of runs
+pub fn bench<S: Solution>(input: &str, runs: usize) -> anyhow::Result<Bench> {
+    let runs = runs.max(1);
+
+    let mut parse = Vec::with_capacity(runs);
+    for _ in 1..runs {
+        let start = Instant::now();
+        black_box(S::parse(input)?);
+        parse.push(start.elapsed());
+    }
+
+    // keep the last parse for the parts
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    parse.push(start.elapsed());
+
+    Ok(Bench::new(S::DAY, runs)
+        .with_phase(PhaseTimings::new(Phase::Parse, parse))
+        .with_phase(PhaseTimings::try_measure(
+            Phase::Part(Part::One),
+            runs,
+            || S::part1(&parsed),
+        )?)
+        .with_phase(PhaseTimings::try_measure(
+            Phase::Part(Part::Two),
+            runs,
+            || S::part2(&parsed),
+        )?))
+}
+
+// Phase
+//##################
+
+/// Measured section of a day
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.write_str("parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+impl Serialize for Phase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+//##################
+
+// PhaseTimings
+//##################
+
+/// Summary of all runs of a phase
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct PhaseTimings {
+    pub phase: Phase,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+impl PhaseTimings {
+    /// Summarize the elapsed times of the runs
+    ///
+    /// Panics if there are no runs
+    pub fn new(phase: Phase, mut runs: Vec<Duration>) -> Self {
+        assert!(!runs.is_empty(), "no runs to summarize");
+        runs.sort_unstable();
+
+        let middle = runs.len() / 2;
+        let median = if runs.len().is_multiple_of(2) {
+            (runs[middle - 1] + runs[middle]) / 2
+        } else {
+            runs[middle]
+        };
+
+        PhaseTimings {
+            phase,
+            min: runs[0],
+            median,
+            max: runs[runs.len() - 1],
+        }
+    }
+
+    /// Run f the given number of times
+    pub fn measure<T>(phase: Phase, runs: usize, mut f: impl FnMut() -> T) -> Self {
+        let runs = (0..runs.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f());
+                start.elapsed()
+            })
+            .collect();
+
+        PhaseTimings::new(phase, runs)
+    }
+
+    /// Run f the given number of times, stopping at the first error
+    pub fn try_measure<T, E>(
+        phase: Phase,
+        runs: usize,
+        mut f: impl FnMut() -> Result<T, E>,
+    ) -> Result<Self, E> {
+        let runs = (0..runs.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f()?);
+                Ok(start.elapsed())
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(PhaseTimings::new(phase, runs))
+    }
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+//##################
+
+// Bench
+//##################
+
+/// Timings of all phases of a day
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Bench {
+    pub day: u8,
+    pub runs: usize,
+    pub phases: Vec<PhaseTimings>,
+}
+
+impl Bench {
+    pub fn new(day: u8, runs: usize) -> Self {
+        Bench {
+            day,
+            runs,
+            phases: Vec::new(),
+        }
+    }
+
+    pub fn with_phase(mut self, phase: PhaseTimings) -> Self {
+        self.phases.push(phase);
+        self
+    }
+}
+
+impl Format {
+    /// Write benchmarks to stdout
+    pub fn print_bench(self, benches: &[Bench]) -> io::Result<()> {
+        let stdout = io::stdout();
+        let mut lock = stdout.lock();
+        self.write_bench(benches, &mut lock)
+    }
+
+    pub fn write_bench(self, benches: &[Bench], out: &mut impl Write) -> io::Result<()> {
+        match self {
+            Format::Text => write_text(benches, out),
+            Format::Json => {
+                serde_json::to_writer_pretty(&mut *out, benches)?;
+                writeln!(out)
+            }
+            Format::Csv => write_csv(benches, out),
+        }
+    }
+}
+
+/// Header per day, one line per phase
+fn write_text(benches: &[Bench], out: &mut impl Write) -> io::Result<()> {
+    for bench in benches {
+        writeln!(out, "Day {:02} ({} runs)", bench.day, bench.runs)?;
+
+        for timings in &bench.phases {
+            writeln!(
+                out,
+                "  {:<8} min {:>12?}  median {:>12?}  max {:>12?}",
+                timings.phase.to_string(),
+                timings.min,
+                timings.median,
+                timings.max
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// One row per phase
+fn write_csv(benches: &[Bench], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "day,phase,runs,min_ns,median_ns,max_ns")?;
+
+    for bench in benches {
+        for timings in &bench.phases {
+            writeln!(
+                out,
+                "{},{},{},{},{},{}",
+                bench.day,
+                csv_field(&timings.phase.to_string()),
+                bench.runs,
+                timings.min.as_nanos(),
+                timings.median.as_nanos(),
+                timings.max.as_nanos()
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+//##################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn summarize_odd() {
+        let timings = PhaseTimings::new(Phase::Parse, millis(&[5, 1, 3]));
+        assert_eq!(timings.min, Duration::from_millis(1));
+        assert_eq!(timings.median, Duration::from_millis(3));
+        assert_eq!(timings.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn summarize_even() {
+        let timings = PhaseTimings::new(Phase::Parse, millis(&[4, 1, 2, 8]));
+        assert_eq!(timings.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn csv() {
+        let bench = Bench::new(8, 3).with_phase(PhaseTimings::new(
+            Phase::Part(Part::Two),
+            millis(&[1, 2, 3]),
+        ));
+
+        let mut out = Vec::new();
+        Format::Csv.write_bench(&[bench], &mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,phase,runs,min_ns,median_ns,max_ns\n8,part 2,3,1000000,2000000,3000000\n"
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::error::Error;
+use std::fmt;
+
+/// Position in the input, line and column are 1-based and count chars
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn new(line: usize, column: usize, len: usize) -> Self {
+        Span { line, column, len }
+    }
+}
+
+// Diagnostic
+//##################
+
+/// Parse error pointing at the input that caused it
+///
+/// Displays rustc-style with the source line and the span underlined:
+/// ```text
+/// unknown instruction "jpm" found in input: jpm +4
+///  --> 3:1
+///   |
+/// 3 | jpm +4
+///   | ^^^^^^
+/// ```
+#[derive(Debug)]
+pub struct Diagnostic {
+    message: String,
+    span: Span,
+    source_line: String,
+    label: Option<String>,
+    error: Option<Box<dyn Error + Send + Sync + 'static>>,
+}
+
+impl Diagnostic {
+    pub fn new(input: &str, span: Span, message: impl Into<String>) -> Self {
+        let source_line = input
+            .lines()
+            .nth(span.line.saturating_sub(1))
+            .unwrap_or_default()
+            .to_owned();
+
+        Diagnostic {
+            message: message.into(),
+            span,
+            source_line,
+            label: None,
+            error: None,
+        }
+    }
+
+    /// Span covers the whole line
+    pub fn at_line(input: &str, line: usize, message: impl Into<String>) -> Self {
+        let len = input
+            .lines()
+            .nth(line.saturating_sub(1))
+            .map_or(0, |content| content.chars().count());
+
+        Diagnostic::new(input, Span::new(line, 1, len), message)
+    }
+
+    /// Span covers fragment, which has to be a slice of input
+    ///
+    /// Points at the start of input if it is not
+    pub fn at_fragment(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + fragment.len() <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = input[line_start..offset].chars().count() + 1;
+        let len = fragment.lines().next().unwrap_or_default().chars().count();
+
+        Diagnostic::new(input, Span::new(line, column, len), message)
+    }
+
+    /// Message is taken from error, which stays available through get_error
+    pub fn from_error<E>(input: &str, span: Span, error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Diagnostic::new(input, span, error.to_string()).with_error(error)
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_error<E>(mut self, error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.error = Some(Box::new(error));
+        self
+    }
+
+    /// Narrow the span to the first occurrence of fragment in the source line
+    pub fn narrow_to(mut self, fragment: &str) -> Self {
+        if let Some(index) = self
+            .source_line
+            .find(fragment)
+            .filter(|_| !fragment.is_empty())
+        {
+            self.span.column = self.source_line[..index].chars().count() + 1;
+            self.span.len = fragment.chars().count();
+        }
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn source_line(&self) -> &str {
+        &self.source_line
+    }
+
+    /// The error this was created from, e.g. for downcasting
+    pub fn get_error(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
+        self.error.as_deref()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.span.line.to_string().len();
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{:gutter$}--> {}:{}",
+            "",
+            self.span.line,
+            self.span.column,
+            gutter = gutter
+        )?;
+        writeln!(f, "{:gutter$} |", "", gutter = gutter)?;
+        writeln!(f, "{} | {}", self.span.line, self.source_line)?;
+        write!(
+            f,
+            "{:gutter$} | {:indent$}{}",
+            "",
+            "",
+            "^".repeat(self.span.len.max(1)),
+            gutter = gutter,
+            indent = self.span.column.saturating_sub(1)
+        )?;
+
+        if let Some(label) = &self.label {
+            write!(f, " {}", label)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for Diagnostic {}
+
+//##################
+
+// Helpers
+//##################
+
+/// Parse every line, a failing line is reported as a whole
+///
+/// Results may borrow from input
+pub fn parse_lines<'a, T, E>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, E>,
+) -> Result<Vec<T>, Diagnostic>
+where
+    E: Error + Send + Sync + 'static,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            parse(line).map_err(|error| {
+                let span = Span::new(index + 1, 1, line.chars().count());
+                Diagnostic::from_error(input, span, error)
+            })
+        })
+        .collect()
+}
+
+/// Parse every item between separators, a failing item is reported on its own
+pub fn parse_separated<'a, T, E>(
+    input: &'a str,
+    separator: char,
+    mut parse: impl FnMut(&'a str) -> Result<T, E>,
+) -> Result<Vec<T>, Diagnostic>
+where
+    E: Error + Send + Sync + 'static,
+{
+    input
+        .split(separator)
+        .map(|item| {
+            parse(item).map_err(|error| {
+                let message = error.to_string();
+                Diagnostic::at_fragment(input, item, message).with_error(error)
+            })
+        })
+        .collect()
+}
+
+//##################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let input = "nop +0\njpm +4";
+        let diagnostic = Diagnostic::at_line(input, 2, "unknown instruction")
+            .narrow_to("jpm")
+            .with_label("expected nop, acc or jmp");
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "unknown instruction\n --> 2:1\n  |\n2 | jpm +4\n  | ^^^ expected nop, acc or jmp"
+        );
+    }
+
+    #[test]
+    fn fragment_position() {
+        let input = "939\n7,13,x,5y";
+        let fragment = &input[11..];
+        let span = Diagnostic::at_fragment(input, fragment, "invalid").span();
+
+        assert_eq!(span, Span::new(2, 8, 2));
+    }
+
+    #[test]
+    fn failing_line() {
+        let error = parse_lines("1\n2\nthree", str::parse::<u32>).unwrap_err();
+
+        assert_eq!(error.span(), Span::new(3, 1, 5));
+        assert_eq!(error.source_line(), "three");
+        assert!(error
+            .get_error()
+            .and_then(|err| err.downcast_ref::<std::num::ParseIntError>())
+            .is_some());
+    }
+
+    #[test]
+    fn failing_item() {
+        let error = parse_separated("0,3,x,6", ',', str::parse::<u32>).unwrap_err();
+        assert_eq!(error.span(), Span::new(1, 5, 1));
+    }
+}
@@ -0,0 +1,311 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::neighbors::Bounds;
+use crate::Point;
+use error::ParseGridError;
+
+/// Rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Empty grid, cells need no default
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given size with every cell set to value
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::new(self.width, self.height)
+    }
+
+    /// Cell at (x, y), None if outside
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// Cell at a signed point, None if outside
+    pub fn get_checked(&self, point: Point<isize>) -> Option<&T> {
+        let point = self.bounds().to_unsigned(point)?;
+        self.get(point.x, point.y)
+    }
+
+    /// Cell at (x, y) with the grid repeating endlessly in every direction
+    ///
+    /// Panics if the grid is empty
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    /// Cell at (x, y) moved to the nearest border if outside
+    ///
+    /// Panics if the grid is empty
+    pub fn get_clamped(&self, x: isize, y: isize) -> &T {
+        let x = x.clamp(0, self.width as isize - 1) as usize;
+        let y = y.clamp(0, self.height as isize - 1) as usize;
+        &self[(x, y)]
+    }
+
+    /// All cells with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, cell)| {
+            let point = Point {
+                x: i % width,
+                y: i / width,
+            };
+            (point, cell)
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, empty grids have no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Grid of the same size with every cell converted
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Rows need the same length, otherwise the 1-based line of the first different one is returned
+    fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, usize> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if let Some(line) = rows.iter().position(|row| row.len() != width) {
+            return Err(line + 1);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    fn expect_index(&self, x: usize, y: usize) -> usize {
+        self.index_of(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) outside of {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+/// Index with (x, y), panics if outside
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.cells[self.expect_index(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let i = self.expect_index(x, y);
+        &mut self.cells[i]
+    }
+}
+
+/// One line per row, every char is converted to a cell
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: std::error::Error + 'static,
+{
+    type Err = ParseGridError<T::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .lines()
+            .enumerate()
+            .map(|(line, content)| {
+                content
+                    .chars()
+                    .enumerate()
+                    .map(|(column, c)| {
+                        T::try_from(c).map_err(|source| ParseGridError::Cell {
+                            line: line + 1,
+                            column: column + 1,
+                            source,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Grid::from_rows(rows).map_err(ParseGridError::LineDifferentLength)
+    }
+}
+
+/// Back to text with one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub mod error {
+    use thiserror::Error;
+
+    use crate::diagnostic::{Diagnostic, Span};
+
+    /// Line and column are 1-based
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum ParseGridError<E: std::error::Error + 'static> {
+        #[error("failed to parse cell at line {line}, column {column}: {source}")]
+        Cell {
+            line: usize,
+            column: usize,
+            #[source]
+            source: E,
+        },
+        #[error("line {0} other length than the ones before")]
+        LineDifferentLength(usize),
+    }
+
+    impl<E: std::error::Error + Send + Sync + 'static> ParseGridError<E> {
+        /// Point at the cell or line in input
+        pub fn into_diagnostic(self, input: &str) -> Diagnostic {
+            match &self {
+                ParseGridError::Cell { line, column, .. } => {
+                    Diagnostic::from_error(input, Span::new(*line, *column, 1), self)
+                }
+                ParseGridError::LineDifferentLength(line) => {
+                    let line = *line;
+                    Diagnostic::at_line(input, line, self.to_string()).with_error(self)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    struct Cell(bool);
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("unknown char {0}")]
+    struct UnknownChar(char);
+
+    impl TryFrom<char> for Cell {
+        type Error = UnknownChar;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '#' => Ok(Cell(true)),
+                '.' => Ok(Cell(false)),
+                c => Err(UnknownChar(c)),
+            }
+        }
+    }
+
+    impl fmt::Display for Cell {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", if self.0 { '#' } else { '.' })
+        }
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let input = "#..\n.#.";
+        let grid = input.parse::<Grid<Cell>>().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], Cell(true));
+        assert_eq!(grid.to_string(), input);
+        assert_eq!(grid.iter().filter(|(_, cell)| cell.0).count(), 2);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            "#..\n.#".parse::<Grid<Cell>>(),
+            Err(ParseGridError::LineDifferentLength(2))
+        ));
+        assert!(matches!(
+            "#..\n.x.".parse::<Grid<Cell>>(),
+            Err(ParseGridError::Cell {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn indexing() {
+        let grid = "#..\n.#.".parse::<Grid<Cell>>().unwrap();
+
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_checked(Point { x: -1, y: 0 }), None);
+        assert_eq!(grid.get_wrapping(4, 3), &Cell(true));
+        assert_eq!(grid.get_wrapping(-3, -2), &Cell(true));
+        assert_eq!(grid.get_clamped(-5, 7), &Cell(false));
+        assert_eq!(grid.get_clamped(9, 9), &Cell(false));
+    }
+}
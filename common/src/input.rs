@@ -0,0 +1,114 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use error::LoadInputError;
+
+/// Where the puzzle input is read from
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Inline(String),
+}
+
+/// "-" means stdin, everything else is a path
+impl FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+/// Read the input from the source and normalize it
+pub fn load(source: &Source) -> Result<String, LoadInputError> {
+    let content = match source {
+        Source::Stdin => {
+            let mut content = String::new();
+            io::stdin()
+                .read_to_string(&mut content)
+                .map_err(LoadInputError::Stdin)?;
+            content
+        }
+        Source::File(path) => fs::read_to_string(path).map_err(|source| LoadInputError::File {
+            path: path.clone(),
+            source,
+        })?,
+        Source::Inline(content) => content.clone(),
+    };
+
+    Ok(normalize(&content))
+}
+
+/// Convert CRLF line endings to LF and strip trailing newlines
+pub fn normalize(content: &str) -> String {
+    content
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_owned()
+}
+
+// Error
+//##################
+
+pub mod error {
+    use std::io;
+    use std::path::PathBuf;
+
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    pub enum LoadInputError {
+        #[error("failed to read input file {}", path.display())]
+        File {
+            path: PathBuf,
+            #[source]
+            source: io::Error,
+        },
+        #[error("failed to read input from stdin")]
+        Stdin(#[source] io::Error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3");
+        assert_eq!(normalize("1\n2\n\n"), "1\n2");
+        assert_eq!(normalize("1\n2"), "1\n2");
+    }
+
+    #[test]
+    fn parse_source() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!(
+            "input/input.txt".parse(),
+            Ok(Source::File(PathBuf::from("input/input.txt")))
+        );
+    }
+
+    #[test]
+    fn load_inline() {
+        let source = Source::Inline("a\r\nb\r\n".to_owned());
+        assert_eq!(load(&source).unwrap(), "a\nb");
+    }
+}
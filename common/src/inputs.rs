@@ -0,0 +1,489 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::input::{self, Source};
+use error::{FetchError, InputsError};
+
+/// Year of the puzzles in this repository
+pub const DEFAULT_YEAR: u16 = 2020;
+
+/// Environment variable overriding the cache directory
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Environment variable holding the adventofcode.com session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Cache directory used if DIR_VAR is not set
+///
+/// `inputs` in the workspace containing the current directory, or in the current
+/// directory itself outside of a workspace
+pub fn default_dir() -> PathBuf {
+    let current = env::current_dir().unwrap_or_default();
+    workspace_root(&current).unwrap_or(current).join("inputs")
+}
+
+/// Closest ancestor of dir, dir included, with a workspace Cargo.toml
+pub fn workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+// Cache
+//##################
+
+/// Puzzle inputs and examples of one year on disk
+///
+/// Layout:
+/// ```text
+/// <dir>/<year>/dayXX/input.txt
+/// <dir>/<year>/dayXX/examples/<name>.txt
+/// ```
+/// Missing inputs are fetched and stored if a fetcher is set
+pub struct InputCache {
+    dir: PathBuf,
+    year: u16,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputCache {
+    /// Cache in dir for DEFAULT_YEAR without fetcher
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache {
+            dir: dir.into(),
+            year: DEFAULT_YEAR,
+            fetcher: None,
+        }
+    }
+
+    /// Cache in DIR_VAR or the default dir, fetching over HTTP if SESSION_VAR is set
+    pub fn from_env() -> Self {
+        let dir = env::var_os(DIR_VAR).map_or_else(default_dir, PathBuf::from);
+        let cache = InputCache::new(dir);
+
+        #[cfg(feature = "http")]
+        if let Ok(session) = env::var(SESSION_VAR) {
+            return cache.with_fetcher(HttpFetcher::new(session));
+        }
+
+        cache
+    }
+
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    pub fn with_year(mut self, year: u16) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(self.year.to_string())
+            .join(format!("day{:02}", day))
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.day_dir(day).join("input.txt")
+    }
+
+    /// Path of the example, name has to be a plain file name without extension
+    pub fn example_path(&self, day: u8, name: &str) -> Result<PathBuf, InputsError> {
+        let plain = !name.is_empty()
+            && !name.contains(['/', '\\'])
+            && matches!(
+                Path::new(name).components().collect::<Vec<_>>().as_slice(),
+                [Component::Normal(_)]
+            );
+        if !plain {
+            return Err(InputsError::InvalidExampleName(name.to_owned()));
+        }
+
+        Ok(self
+            .day_dir(day)
+            .join("examples")
+            .join(format!("{}.txt", name)))
+    }
+
+    /// Path of the input, fetched first if it is not cached yet
+    pub fn ensure(&self, day: u8) -> Result<PathBuf, InputsError> {
+        let path = self.input_path(day);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let fetcher = self
+            .fetcher
+            .as_ref()
+            .ok_or_else(|| InputsError::NotCached { path: path.clone() })?;
+        let content = fetcher
+            .fetch(self.year, day)
+            .map_err(|source| InputsError::Fetch { day, source })?;
+
+        write(&path, &content)?;
+        Ok(path)
+    }
+
+    /// Source reading the cached input
+    pub fn source(&self, day: u8) -> Result<Source, InputsError> {
+        self.ensure(day).map(Source::File)
+    }
+
+    /// Normalized input of the day
+    pub fn input(&self, day: u8) -> Result<String, InputsError> {
+        let path = self.ensure(day)?;
+        read(&path)
+    }
+
+    /// Normalized example of the day
+    pub fn example(&self, day: u8, name: &str) -> Result<String, InputsError> {
+        read(&self.example_path(day, name)?)
+    }
+
+    /// Names of all stored examples of the day, sorted
+    pub fn examples(&self, day: u8) -> Result<Vec<String>, InputsError> {
+        let dir = self.day_dir(day).join("examples");
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => return Err(InputsError::Read { path: dir, source }),
+        };
+
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|source| InputsError::Read {
+                    path: dir.clone(),
+                    source,
+                })?
+                .path();
+            if path.extension() == Some("txt".as_ref()) {
+                if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(name.to_owned());
+                }
+            }
+        }
+
+        names.sort();
+        Ok(names)
+    }
+
+    /// Store an example copied from the puzzle text, returns its path
+    pub fn store_example(
+        &self,
+        day: u8,
+        name: &str,
+        content: &str,
+    ) -> Result<PathBuf, InputsError> {
+        let path = self.example_path(day, name)?;
+        write(&path, &format!("{}\n", input::normalize(content)))?;
+        Ok(path)
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputsError> {
+    fs::read_to_string(path)
+        .map(|content| input::normalize(&content))
+        .map_err(|source| InputsError::Read {
+            path: path.to_owned(),
+            source,
+        })
+}
+
+fn write(path: &Path, content: &str) -> Result<(), InputsError> {
+    let result = match path.parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|_| fs::write(path, content)),
+        None => fs::write(path, content),
+    };
+
+    result.map_err(|source| InputsError::Write {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+//##################
+
+// Fetcher
+//##################
+
+/// Provides puzzle inputs that are not cached yet
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+/// Closures can be used as fetchers, e.g. in tests
+impl<F> Fetcher for F
+where
+    F: Fn(u16, u8) -> Result<String, FetchError>,
+{
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self(year, day)
+    }
+}
+
+/// Inputs stored as `<root>/<year>/dayXX.txt`, e.g. from another checkout
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LocalDir {
+    root: PathBuf,
+}
+
+impl LocalDir {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        LocalDir { root: root.into() }
+    }
+}
+
+impl Fetcher for LocalDir {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = self
+            .root
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day));
+
+        fs::read_to_string(&path).map_err(|source| FetchError::Io { path, source })
+    }
+}
+
+/// Downloads inputs from adventofcode.com, needs the session cookie of a logged in user
+#[cfg(feature = "http")]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+#[cfg(feature = "http")]
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(session: impl Into<String>) -> Self {
+        HttpFetcher {
+            base_url: HttpFetcher::BASE_URL.to_owned(),
+            session: session.into(),
+        }
+    }
+
+    /// Use another server, e.g. a mock server in tests
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => FetchError::Status {
+                    url: url.clone(),
+                    status,
+                },
+                err => FetchError::Http {
+                    url: url.clone(),
+                    message: err.to_string(),
+                },
+            })?;
+
+        response.into_string().map_err(|err| FetchError::Http {
+            url,
+            message: err.to_string(),
+        })
+    }
+}
+
+//##################
+
+// Error
+//##################
+
+pub mod error {
+    use std::io;
+    use std::path::PathBuf;
+
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    pub enum InputsError {
+        #[error("input {} is not cached and there is no fetcher, set AOC_SESSION to download it", path.display())]
+        NotCached { path: PathBuf },
+        #[error("example name \"{0}\" has to be a plain file name")]
+        InvalidExampleName(String),
+        #[error("failed to fetch input of day {day}")]
+        Fetch {
+            day: u8,
+            #[source]
+            source: FetchError,
+        },
+        #[error("failed to read {}", path.display())]
+        Read {
+            path: PathBuf,
+            #[source]
+            source: io::Error,
+        },
+        #[error("failed to write {}", path.display())]
+        Write {
+            path: PathBuf,
+            #[source]
+            source: io::Error,
+        },
+    }
+
+    #[derive(Error, Debug)]
+    pub enum FetchError {
+        #[error("request to {url} failed: {message}")]
+        Http { url: String, message: String },
+        #[error("request to {url} failed with status {status}")]
+        Status { url: String, status: u16 },
+        #[error("failed to read {}", path.display())]
+        Io {
+            path: PathBuf,
+            #[source]
+            source: io::Error,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetch_once() {
+        let dir = temp_dir("fetch");
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+
+        let cache = InputCache::new(&dir).with_fetcher(move |year, day| {
+            counter.set(counter.get() + 1);
+            Ok(format!("{} {}\r\n", year, day))
+        });
+
+        assert_eq!(cache.input(3).unwrap(), "2020 3");
+        assert_eq!(cache.input(3).unwrap(), "2020 3");
+        assert_eq!(calls.get(), 1);
+        assert!(dir.join("2020").join("day03").join("input.txt").is_file());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn workspace() {
+        let root = workspace_root(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert!(root.join("common").is_dir());
+        assert_eq!(workspace_root(&root.join("common").join("src")), Some(root));
+    }
+
+    #[test]
+    fn not_cached() {
+        let cache = InputCache::new(temp_dir("missing"));
+        assert!(matches!(cache.input(1), Err(InputsError::NotCached { .. })));
+    }
+
+    #[test]
+    fn examples() {
+        let dir = temp_dir("examples");
+        let cache = InputCache::new(&dir).with_fetcher(LocalDir::new(dir.join("local")));
+
+        cache.store_example(6, "2", "a\n\nb\n").unwrap();
+        cache.store_example(6, "1", "abc").unwrap();
+        assert_eq!(cache.examples(6).unwrap(), vec!["1", "2"]);
+        assert_eq!(cache.example(6, "2").unwrap(), "a\n\nb");
+        assert!(cache.examples(7).unwrap().is_empty());
+        for name in ["../escape", "a/b", "..", "", "/abs"] {
+            assert!(matches!(
+                cache.store_example(6, name, "x"),
+                Err(InputsError::InvalidExampleName(_))
+            ));
+        }
+
+        assert!(matches!(
+            cache.input(6),
+            Err(InputsError::Fetch {
+                source: FetchError::Io { .. },
+                ..
+            })
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn mock_server() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                request.push(line.trim_end().to_owned());
+            }
+
+            let body = "1721\n979\n";
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        let fetcher = HttpFetcher::new("secret").with_base_url(format!("http://{}", address));
+        assert_eq!(fetcher.fetch(2020, 1).unwrap(), "1721\n979\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2020/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|line| line == "Cookie: session=secret"));
+    }
+}
@@ -1,4 +1,19 @@
+pub mod answers;
+pub mod bench;
+pub mod diagnostic;
+pub mod grid;
+pub mod input;
+pub mod inputs;
+pub mod neighbors;
+pub mod report;
 pub mod simple_cli;
+pub mod solution;
+pub mod testing;
 
 mod point;
+mod point_n;
+pub use grid::Grid;
 pub use point::Point;
+pub use point_n::PointN;
+pub use report::{Format, Report};
+pub use solution::Solution;
@@ -0,0 +1,264 @@
+use std::convert::TryFrom;
+
+use crate::{Point, PointN};
+
+// Neighborhood
+//##################
+
+/// Shape of the area around a point
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Neighborhood {
+    /// Points within the given Manhattan distance
+    VonNeumann(usize),
+    /// Points within the given Chebyshev distance
+    Moore(usize),
+}
+
+impl Neighborhood {
+    /// Offsets of all neighbors in 2D, without the center
+    pub fn offsets(self) -> impl Iterator<Item = Point<isize>> {
+        self.offsets_n::<2>().map(Point::from)
+    }
+
+    /// Offsets of all neighbors in N dimensions, without the center
+    pub fn offsets_n<const N: usize>(self) -> impl Iterator<Item = PointN<isize, N>> {
+        let radius = self.radius() as isize;
+        let side = (2 * radius + 1) as usize;
+        let count = side.pow(N as u32);
+
+        // every index in the surrounding hypercube encodes one offset with base side
+        (0..count)
+            .map(move |i| {
+                let mut rest = i;
+                let mut offset = PointN::origin();
+                for coord in offset.coords.iter_mut() {
+                    *coord = (rest % side) as isize - radius;
+                    rest /= side;
+                }
+                offset
+            })
+            .filter(move |offset| *offset != PointN::origin() && self.contains(offset))
+    }
+
+    /// All neighbors of center
+    pub fn around(self, center: Point<isize>) -> impl Iterator<Item = Point<isize>> {
+        self.offsets().map(move |offset| center + offset)
+    }
+
+    pub fn radius(self) -> usize {
+        match self {
+            Neighborhood::VonNeumann(radius) | Neighborhood::Moore(radius) => radius,
+        }
+    }
+
+    fn contains<const N: usize>(self, offset: &PointN<isize, N>) -> bool {
+        match self {
+            Neighborhood::VonNeumann(radius) => offset.manhattan_norm() as usize <= radius,
+            Neighborhood::Moore(radius) => offset.chebyshev_norm() as usize <= radius,
+        }
+    }
+}
+
+//##################
+
+// Direction
+//##################
+
+/// Compass direction, north is towards negative y
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All directions clockwise starting north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// North, east, south and west
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Step of length one in this direction
+    pub fn offset(self) -> Point<isize> {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        Point { x, y }
+    }
+
+    /// Rotate clockwise by steps of 45°, negative steps rotate counterclockwise
+    pub fn rotate(self, steps: isize) -> Direction {
+        let index = Direction::ALL.iter().position(|d| *d == self).unwrap() as isize;
+        Direction::ALL[(index + steps).rem_euclid(8) as usize]
+    }
+
+    /// Rotate 90° clockwise
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotate 90° counterclockwise
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        Direction::CARDINAL.contains(&self)
+    }
+}
+
+//##################
+
+// Ray
+//##################
+
+/// Endless walk from a start point, the start itself is not included
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Ray {
+    current: Point<isize>,
+    step: Point<isize>,
+}
+
+impl Ray {
+    pub fn new(start: Point<isize>, direction: Direction) -> Self {
+        Ray {
+            current: start,
+            step: direction.offset(),
+        }
+    }
+}
+
+impl Iterator for Ray {
+    type Item = Point<isize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current += self.step;
+        Some(self.current)
+    }
+}
+
+//##################
+
+// Bounds
+//##################
+
+/// Size of a grid with the origin in the top left corner
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Bounds {
+    pub fn new(width: usize, height: usize) -> Self {
+        Bounds { width, height }
+    }
+
+    pub fn contains(&self, point: Point<isize>) -> bool {
+        self.to_unsigned(point).is_some()
+    }
+
+    /// Point as usize coordinates if it is inside
+    pub fn to_unsigned(&self, point: Point<isize>) -> Option<Point<usize>> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(Point { x, y })
+    }
+
+    /// Neighbors of center that are inside
+    pub fn neighbors(
+        self,
+        center: Point<isize>,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Point<isize>> {
+        neighborhood
+            .around(center)
+            .filter(move |point| self.contains(*point))
+    }
+
+    /// Ray from start until it leaves the bounds
+    pub fn ray(
+        self,
+        start: Point<isize>,
+        direction: Direction,
+    ) -> impl Iterator<Item = Point<isize>> {
+        Ray::new(start, direction).take_while(move |point| self.contains(*point))
+    }
+}
+
+//##################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighborhood_sizes() {
+        assert_eq!(Neighborhood::Moore(1).offsets().count(), 8);
+        assert_eq!(Neighborhood::Moore(2).offsets().count(), 24);
+        assert_eq!(Neighborhood::VonNeumann(1).offsets().count(), 4);
+        assert_eq!(Neighborhood::VonNeumann(2).offsets().count(), 12);
+        assert_eq!(Neighborhood::Moore(1).offsets_n::<3>().count(), 26);
+        assert_eq!(Neighborhood::VonNeumann(1).offsets_n::<3>().count(), 6);
+    }
+
+    #[test]
+    fn rotation() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthWest.rotate(1), Direction::North);
+        assert_eq!(Direction::East.rotate(-3), Direction::NorthWest);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.offset() + d.opposite().offset() == Point { x: 0, y: 0 }));
+    }
+
+    #[test]
+    fn bounded() {
+        let bounds = Bounds::new(3, 2);
+
+        let corner = bounds
+            .neighbors(Point { x: 0, y: 0 }, Neighborhood::Moore(1))
+            .count();
+        assert_eq!(corner, 3);
+
+        let ray = bounds
+            .ray(Point { x: 0, y: 0 }, Direction::SouthEast)
+            .collect::<Vec<_>>();
+        assert_eq!(ray, vec![Point { x: 1, y: 1 }]);
+
+        assert_eq!(bounds.ray(Point { x: 0, y: 1 }, Direction::East).count(), 2);
+    }
+}
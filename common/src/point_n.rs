@@ -0,0 +1,285 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::Point;
+
+/// Point with N dimensions
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PointN<T, const N: usize> {
+    pub coords: [T; N],
+}
+
+impl<T, const N: usize> PointN<T, N> {
+    pub const DIM: usize = N;
+
+    pub fn new(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+}
+
+impl<T: Copy + Default, const N: usize> PointN<T, N> {
+    /// Point with all coordinates zero
+    pub fn origin() -> Self {
+        Self {
+            coords: [T::default(); N],
+        }
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Default for PointN<T, N> {
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+// Norms
+//##################
+
+impl<T, const N: usize> PointN<T, N>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    /// Sum of the absolute differences of all coordinates
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.axis_distances(other)
+            .fold(T::default(), |sum, distance| sum + distance)
+    }
+
+    /// Largest absolute difference of all coordinates
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.axis_distances(other).max().unwrap_or_default()
+    }
+
+    /// Manhattan distance to the origin
+    pub fn manhattan_norm(&self) -> T {
+        self.manhattan_distance(&Self::origin())
+    }
+
+    /// Chebyshev distance to the origin
+    pub fn chebyshev_norm(&self) -> T {
+        self.chebyshev_distance(&Self::origin())
+    }
+
+    // max - min works for unsigned types as well
+    fn axis_distances<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = T> + 'a {
+        self.coords
+            .iter()
+            .zip(other.coords.iter())
+            .map(|(&a, &b)| max(a, b) - min(a, b))
+    }
+}
+
+//##################
+
+// Neighbors
+//##################
+
+impl<T, const N: usize> PointN<T, N>
+where
+    T: Copy + Add<Output = T> + From<i8>,
+{
+    /// All 3^N - 1 points that differ by at most one in every coordinate
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        let count = 3_usize.pow(N as u32);
+        let center = count / 2;
+
+        (0..count).filter(move |&i| i != center).map(move |i| {
+            let mut rest = i;
+            let mut point = self;
+            for coord in point.coords.iter_mut() {
+                *coord = *coord + T::from((rest % 3) as i8 - 1);
+                rest /= 3;
+            }
+            point
+        })
+    }
+
+    /// The 2N points that differ by one in exactly one coordinate
+    pub fn orthogonal_neighbors(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            [-1, 1].iter().map(move |&step| {
+                let mut point = self;
+                point.coords[axis] = point.coords[axis] + T::from(step);
+                point
+            })
+        })
+    }
+}
+
+//##################
+
+// Conversions
+//##################
+
+impl<T, const N: usize> From<[T; N]> for PointN<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+}
+
+/// x and y are kept, the other coordinates are zero
+impl<T, const N: usize> From<Point<T>> for PointN<T, N>
+where
+    T: Copy + Default + Add<Output = T> + AddAssign + Sub<Output = T> + SubAssign,
+{
+    fn from(point: Point<T>) -> Self {
+        const { assert!(N >= 2, "a 2D point needs at least two dimensions") };
+
+        let mut result = Self::origin();
+        result.coords[0] = point.x;
+        result.coords[1] = point.y;
+        result
+    }
+}
+
+impl<T> From<PointN<T, 2>> for Point<T>
+where
+    T: Copy + Add<Output = T> + AddAssign + Sub<Output = T> + SubAssign,
+{
+    fn from(point: PointN<T, 2>) -> Self {
+        let [x, y] = point.coords;
+        Point { x, y }
+    }
+}
+
+//##################
+
+// Arithmetic
+//##################
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for PointN<T, N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> AddAssign for PointN<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (coord, other) in self.coords.iter_mut().zip(rhs.coords.iter()) {
+            *coord = *coord + *other;
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for PointN<T, N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> SubAssign for PointN<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (coord, other) in self.coords.iter_mut().zip(rhs.coords.iter()) {
+            *coord = *coord - *other;
+        }
+    }
+}
+
+/// Scale every coordinate
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for PointN<T, N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        for coord in self.coords.iter_mut() {
+            *coord = *coord * rhs;
+        }
+        self
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for PointN<T, N> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for coord in self.coords.iter_mut() {
+            *coord = -*coord;
+        }
+        self
+    }
+}
+
+impl<T, const N: usize> Index<usize> for PointN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.coords[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PointN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.coords[axis]
+    }
+}
+
+//##################
+
+/// "(x, y, ...)"
+impl<T: fmt::Display, const N: usize> fmt::Display for PointN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, coord) in self.coords.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", coord)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = PointN::new([1, 2, 3]);
+        let b = PointN::new([-1, 0, 5]);
+
+        assert_eq!(a + b, PointN::new([0, 2, 8]));
+        assert_eq!(a - b, PointN::new([2, 2, -2]));
+        assert_eq!(-a * 2, PointN::new([-2, -4, -6]));
+        assert_eq!(a[2], 3);
+    }
+
+    #[test]
+    fn norms() {
+        let a = PointN::new([1_isize, -4, 2]);
+        assert_eq!(a.manhattan_norm(), 7);
+        assert_eq!(a.chebyshev_norm(), 4);
+
+        let b = PointN::new([3_usize, 1]);
+        let c = PointN::new([1_usize, 5]);
+        assert_eq!(b.manhattan_distance(&c), 6);
+        assert_eq!(b.chebyshev_distance(&c), 4);
+    }
+
+    #[test]
+    fn neighbors() {
+        let origin = PointN::<isize, 4>::origin();
+        let neighbors = origin.neighbors().collect::<Vec<_>>();
+
+        assert_eq!(neighbors.len(), 80);
+        assert!(!neighbors.contains(&origin));
+        assert!(neighbors.iter().all(|n| n.chebyshev_norm() == 1));
+
+        let orthogonal = origin.orthogonal_neighbors().collect::<Vec<_>>();
+        assert_eq!(orthogonal.len(), 8);
+        assert!(orthogonal.iter().all(|n| n.manhattan_norm() == 1));
+    }
+
+    #[test]
+    fn from_point() {
+        let point = Point { x: 3_isize, y: -1 };
+        assert_eq!(PointN::<_, 3>::from(point), PointN::new([3, -1, 0]));
+        assert_eq!(Point::from(PointN::<_, 2>::from(point)), point);
+    }
+}
@@ -0,0 +1,365 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+pub use error::ParseFormatError;
+
+// Answer
+//##################
+
+/// Answer of a part
+///
+/// Displays as the answer itself, extra fields carry values that lead to it
+pub trait Answer: fmt::Display {
+    /// Named values besides the answer
+    fn extra(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+
+impl_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+
+//##################
+
+// Part
+//##################
+
+/// Identifies a part of a day
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Part {
+    One,
+    Two,
+    /// Additions beyond the puzzle
+    Extra(String),
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+            Part::Extra(name) => f.write_str(name),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+//##################
+
+// PartResult
+//##################
+
+/// Answer of a single part with the time it took
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct PartResult {
+    pub part: Part,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub answer: String,
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Duration,
+    #[serde(serialize_with = "serialize_extra")]
+    pub extra: Vec<(String, String)>,
+}
+
+impl PartResult {
+    pub fn new<A: Answer>(part: Part, answer: &A, elapsed: Duration) -> Self {
+        Self {
+            part,
+            label: None,
+            answer: answer.to_string(),
+            elapsed,
+            extra: answer.extra(),
+        }
+    }
+
+    /// Run solve and take the time it needs
+    pub fn timed<A: Answer>(part: Part, solve: impl FnOnce() -> A) -> Self {
+        let start = Instant::now();
+        let answer = solve();
+        Self::new(part, &answer, start.elapsed())
+    }
+
+    /// Same as timed, but for a solve that can fail
+    pub fn try_timed<A: Answer>(
+        part: Part,
+        solve: impl FnOnce() -> anyhow::Result<A>,
+    ) -> anyhow::Result<Self> {
+        let start = Instant::now();
+        let answer = solve()?;
+        Ok(Self::new(part, &answer, start.elapsed()))
+    }
+
+    /// Label for the text format, falls back to "Part X"
+    pub fn get_label(&self) -> String {
+        match &self.label {
+            Some(label) => label.to_owned(),
+            None => format!("Part {}", self.part),
+        }
+    }
+}
+
+fn serialize_nanos<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(elapsed.as_nanos())
+}
+
+fn serialize_extra<S: Serializer>(
+    extra: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(extra.len()))?;
+    for (key, value) in extra {
+        map.serialize_entry(key, value)?;
+    }
+    map.end()
+}
+
+//##################
+
+// Report
+//##################
+
+/// Results of all parts of a day
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub parts: Vec<PartResult>,
+}
+
+impl Report {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            parts: Vec::new(),
+        }
+    }
+
+    pub fn with_part(mut self, part: PartResult) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    /// Set the label of the given part for the text format
+    pub fn with_label(mut self, part: Part, label: impl Into<String>) -> Self {
+        if let Some(result) = self.parts.iter_mut().find(|p| p.part == part) {
+            result.label = Some(label.into());
+        }
+        self
+    }
+
+    pub fn get_part(&self, part: &Part) -> Option<&PartResult> {
+        self.parts.iter().find(|p| p.part == *part)
+    }
+}
+
+//##################
+
+// Format
+//##################
+
+/// Output format of reports
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Write reports to stdout
+    pub fn print(self, reports: &[Report]) -> io::Result<()> {
+        let stdout = io::stdout();
+        let mut lock = stdout.lock();
+        self.write(reports, &mut lock)
+    }
+
+    pub fn write(self, reports: &[Report], out: &mut impl Write) -> io::Result<()> {
+        match self {
+            Format::Text => write_text(reports, out),
+            Format::Json => {
+                serde_json::to_writer_pretty(&mut *out, reports)?;
+                writeln!(out)
+            }
+            Format::Csv => write_csv(reports, out),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(ParseFormatError::UnknownFormat(s.to_owned())),
+        }
+    }
+}
+
+/// One line per part: "label: answer (extra: value, ...)"
+/// Multiple reports get a header per day
+fn write_text(reports: &[Report], out: &mut impl Write) -> io::Result<()> {
+    let with_header = reports.len() > 1;
+
+    for report in reports {
+        if with_header {
+            writeln!(out, "Day {:02}", report.day)?;
+        }
+
+        for part in &report.parts {
+            if with_header {
+                write!(out, "  ")?;
+            }
+
+            write!(out, "{}: {}", part.get_label(), part.answer)?;
+
+            if !part.extra.is_empty() {
+                let extra = part
+                    .extra
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", key, value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(out, " ({})", extra)?;
+            }
+
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// One row per part, extra fields joined as "key=value;key=value"
+fn write_csv(reports: &[Report], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "day,part,answer,elapsed_ns,extra")?;
+
+    for report in reports {
+        for part in &report.parts {
+            let extra = part
+                .extra
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>()
+                .join(";");
+
+            writeln!(
+                out,
+                "{},{},{},{},{}",
+                report.day,
+                csv_field(&part.part.to_string()),
+                csv_field(&part.answer),
+                part.elapsed.as_nanos(),
+                csv_field(&extra)
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Quote field if needed
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+//##################
+
+pub mod error {
+    use thiserror::Error;
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum ParseFormatError {
+        #[error("unknown format \"{0}\", expected one of: text, json, csv")]
+        UnknownFormat(String),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<Report> {
+        vec![Report {
+            day: 13,
+            parts: vec![PartResult {
+                part: Part::One,
+                label: Some("Bus ID multiplied by wait time".to_owned()),
+                answer: "295".to_owned(),
+                elapsed: Duration::from_nanos(1500),
+                extra: vec![
+                    ("bus_id".to_owned(), "59".to_owned()),
+                    ("wait_time".to_owned(), "5".to_owned()),
+                ],
+            }],
+        }]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        format.write(&example(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(Format::Text),
+            "Bus ID multiplied by wait time: 295 (bus_id: 59, wait_time: 5)\n"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv),
+            "day,part,answer,elapsed_ns,extra\n13,1,295,1500,bus_id=59;wait_time=5\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let value: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([{
+                "day": 13,
+                "parts": [{
+                    "part": "1",
+                    "label": "Bus ID multiplied by wait time",
+                    "answer": "295",
+                    "elapsed_ns": 1500,
+                    "extra": { "bus_id": "59", "wait_time": "5" }
+                }]
+            }])
+        );
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("JSON".parse::<Format>(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
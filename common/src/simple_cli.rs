@@ -1,17 +1,43 @@
 use clap::Clap;
 
+use crate::input::Source;
+use crate::inputs::{error::InputsError, InputCache};
+use crate::report::Format;
+use crate::Solution;
+
 #[derive(Clap)]
 #[clap(
     version = "1.0",
     author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
 )]
 pub struct Opts {
-    /// Path to input file
-    pub input: String,
+    /// Path to input file, "-" reads from stdin. Defaults to the cached input of the day
+    pub input: Option<Source>,
+
+    /// Puzzle input given inline instead of a file
+    #[clap(long, conflicts_with = "input")]
+    pub input_string: Option<String>,
+
+    /// Output format: text, json or csv
+    #[clap(short, long, default_value = "text")]
+    pub format: Format,
+
+    /// Time parse, part 1 and part 2 over N runs instead of printing the answers
+    #[clap(long, value_name = "N")]
+    pub bench: Option<usize>,
 }
 
 impl Opts {
     pub fn get() -> Self {
         Opts::parse()
     }
+
+    /// Where to read the puzzle input of S from, the input cache if none is given
+    pub fn source_for<S: Solution>(&self) -> Result<Source, InputsError> {
+        match (&self.input, &self.input_string) {
+            (_, Some(content)) => Ok(Source::Inline(content.clone())),
+            (Some(source), None) => Ok(source.clone()),
+            (None, None) => InputCache::from_env().source(S::DAY),
+        }
+    }
 }
@@ -0,0 +1,34 @@
+use crate::report::{Answer, Part, PartResult, Report};
+
+/// Solution of a single day
+///
+/// Splits a day into parsing the puzzle input and solving both parts on the parsed input,
+/// so that every day can be run in-process by a runner
+pub trait Solution {
+    /// Day of the puzzle
+    const DAY: u8;
+
+    /// Parsed puzzle input, may borrow from the input string
+    type Input<'a>;
+
+    /// Answer of part 1
+    type Part1: Answer;
+
+    /// Answer of part 2
+    type Part2: Answer;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1>;
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2>;
+}
+
+/// Parse input and solve both parts with the given Solution
+pub fn solve<S: Solution>(input: &str) -> anyhow::Result<Report> {
+    let parsed = S::parse(input)?;
+
+    Ok(Report::new(S::DAY)
+        .with_part(PartResult::try_timed(Part::One, || S::part1(&parsed))?)
+        .with_part(PartResult::try_timed(Part::Two, || S::part2(&parsed))?))
+}
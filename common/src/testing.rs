@@ -0,0 +1,69 @@
+use std::fmt;
+
+use crate::input;
+use crate::Solution;
+
+/// Generate a test per part that solves an example and compares the answers
+///
+/// Parts without expected answer are not run, e.g. if the example only covers one of them.
+/// ```ignore
+/// common::example_tests! {
+///     Day01,
+///     example_1: common::example_input!("1") => { part1: 514579, part2: 241861950 },
+///     inline: "1010\n1010" => { part1: 1020100 },
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (
+        $solution:ty,
+        $( $name:ident : $input:expr => { $( $part:ident : $expected:expr ),+ $(,)? } ),+ $(,)?
+    ) => {
+        $(
+            mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $(
+                    #[test]
+                    fn $part() {
+                        $crate::testing::$part::<$solution>($input, $expected);
+                    }
+                )+
+            }
+        )+
+    };
+}
+
+/// Example stored in the input cache of the calling day crate, see common::inputs
+///
+/// Resolved at compile time, so the file needs to exist
+#[macro_export]
+macro_rules! example_input {
+    ($name:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../inputs/2020/",
+            env!("CARGO_PKG_NAME"),
+            "/examples/",
+            $name,
+            ".txt"
+        ))
+    };
+}
+
+/// Parse input and compare the answer of part 1 with expected, panics on mismatch
+pub fn part1<S: Solution>(input: &str, expected: impl fmt::Display) {
+    let input = input::normalize(input);
+    let parsed = S::parse(&input).expect("example should parse");
+    let answer = S::part1(&parsed).expect("part 1 should be solvable");
+    assert_eq!(answer.to_string(), expected.to_string(), "part 1");
+}
+
+/// Parse input and compare the answer of part 2 with expected, panics on mismatch
+pub fn part2<S: Solution>(input: &str, expected: impl fmt::Display) {
+    let input = input::normalize(input);
+    let parsed = S::parse(&input).expect("example should parse");
+    let answer = S::part2(&parsed).expect("part 2 should be solvable");
+    assert_eq!(answer.to_string(), expected.to_string(), "part 2");
+}
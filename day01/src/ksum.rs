@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use itertools::Itertools;
+
+/// Integer types the solver works with
+///
+/// Values are widened to i128, so sums of entries can neither overflow nor underflow
+pub trait Number: Copy + Ord + Hash + fmt::Debug {
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+        })*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Whether to stop at the first solution or collect all of them
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Search {
+    First,
+    All,
+}
+
+/// Search for k entries that sum up to a target
+///
+/// Solutions are the indices of the entries, ascending, so equal values at different positions
+/// are different solutions. With Search::All the solutions are sorted as well, with Search::First
+/// the one found first is returned, which depends on the algorithm used for k
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct KSum<T> {
+    target: T,
+    k: usize,
+    search: Search,
+    reuse: bool,
+}
+
+impl<T: Number> KSum<T> {
+    /// Search the first solution, every entry may be used once
+    pub fn new(target: T, k: usize) -> Self {
+        KSum {
+            target,
+            k,
+            search: Search::First,
+            reuse: false,
+        }
+    }
+
+    pub fn with_search(mut self, search: Search) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Allow using the same entry more than once, its index is repeated in the solution
+    pub fn with_reuse(mut self, reuse: bool) -> Self {
+        self.reuse = reuse;
+        self
+    }
+
+    pub fn solve(&self, values: &[T]) -> Vec<Vec<usize>> {
+        let values = values.iter().map(|v| v.to_i128()).collect::<Vec<_>>();
+        let target = self.target.to_i128();
+
+        let mut solutions = match self.k {
+            0 if target == 0 => vec![Vec::new()],
+            0 => Vec::new(),
+            1 => (0..values.len())
+                .filter(|&i| values[i] == target)
+                .map(|i| vec![i])
+                .take(self.limit())
+                .collect(),
+            2 => self.two_sum(&values, target),
+            3 => self.three_sum(&values, target),
+            _ => self.meet_in_the_middle(&values, target),
+        };
+
+        if self.search == Search::All {
+            solutions.sort_unstable();
+        }
+        solutions
+    }
+
+    fn limit(&self) -> usize {
+        match self.search {
+            Search::First => 1,
+            Search::All => usize::MAX,
+        }
+    }
+
+    /// Remember where every value was seen so far and look up the missing one, O(n)
+    fn two_sum(&self, values: &[i128], target: i128) -> Vec<Vec<usize>> {
+        let mut seen = HashMap::<i128, Vec<usize>>::new();
+        let mut solutions = Vec::new();
+
+        for (second, &value) in values.iter().enumerate() {
+            if self.reuse {
+                seen.entry(value).or_default().push(second);
+            }
+            if let Some(firsts) = seen.get(&(target - value)) {
+                for &first in firsts {
+                    solutions.push(vec![first, second]);
+                    if solutions.len() == self.limit() {
+                        return solutions;
+                    }
+                }
+            }
+            if !self.reuse {
+                seen.entry(value).or_default().push(second);
+            }
+        }
+
+        solutions
+    }
+
+    /// Fix the smallest entry and close in on the other two from both ends, O(n²)
+    ///
+    /// Equal values form runs in the sorted order, every combination of them is a solution
+    fn three_sum(&self, values: &[i128], target: i128) -> Vec<Vec<usize>> {
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| values[i]);
+        let value = |position: usize| values[order[position]];
+
+        let mut solutions = Vec::new();
+        // store the solution, true if enough are found
+        let push = |solutions: &mut Vec<Vec<usize>>, positions: [usize; 3]| {
+            let mut indices = positions.iter().map(|&p| order[p]).collect::<Vec<_>>();
+            indices.sort_unstable();
+            solutions.push(indices);
+            solutions.len() == self.limit()
+        };
+
+        for first in 0..order.len() {
+            let rest = target - value(first);
+            let mut low = if self.reuse { first } else { first + 1 };
+            let mut high = order.len();
+
+            while low < high {
+                let sum = value(low) + value(high - 1);
+                if sum < rest {
+                    low += 1;
+                } else if sum > rest {
+                    high -= 1;
+                } else if value(low) == value(high - 1) {
+                    // all remaining positions hold the same value
+                    let run = low..high;
+                    let pairs = if self.reuse {
+                        run.combinations_with_replacement(2).collect::<Vec<_>>()
+                    } else {
+                        run.combinations(2).collect()
+                    };
+                    for pair in pairs {
+                        if push(&mut solutions, [first, pair[0], pair[1]]) {
+                            return solutions;
+                        }
+                    }
+                    break;
+                } else {
+                    let low_end = (low..high).find(|&p| value(p) != value(low)).unwrap();
+                    let high_start = (low..high)
+                        .rev()
+                        .find(|&p| value(p) != value(high - 1))
+                        .unwrap()
+                        + 1;
+
+                    for second in low..low_end {
+                        for third in high_start..high {
+                            if push(&mut solutions, [first, second, third]) {
+                                return solutions;
+                            }
+                        }
+                    }
+                    low = low_end;
+                    high = high_start;
+                }
+            }
+        }
+
+        solutions
+    }
+
+    /// Split k into two halves, store the sums of all index sets of the first size and look up
+    /// the missing sum for every index set of the second size, O(n^⌈k/2⌉)
+    ///
+    /// Every solution is only found as its lower indices joined with its upper ones
+    fn meet_in_the_middle(&self, values: &[i128], target: i128) -> Vec<Vec<usize>> {
+        let left_size = self.k / 2;
+        let right_size = self.k - left_size;
+
+        let index_sets = |size: usize| -> Box<dyn Iterator<Item = Vec<usize>>> {
+            if self.reuse {
+                Box::new((0..values.len()).combinations_with_replacement(size))
+            } else {
+                Box::new((0..values.len()).combinations(size))
+            }
+        };
+        let sum = |indices: &[usize]| indices.iter().map(|&i| values[i]).sum::<i128>();
+
+        let mut by_sum = HashMap::<i128, Vec<Vec<usize>>>::new();
+        for left in index_sets(left_size) {
+            by_sum.entry(sum(&left)).or_default().push(left);
+        }
+
+        let mut solutions = Vec::new();
+        for right in index_sets(right_size) {
+            let lefts = match by_sum.get(&(target - sum(&right))) {
+                Some(lefts) => lefts,
+                None => continue,
+            };
+
+            let joinable = |left: &&Vec<usize>| match (left.last(), right.first()) {
+                (Some(last), Some(first)) if self.reuse => last <= first,
+                (Some(last), Some(first)) => last < first,
+                _ => true,
+            };
+            for left in lefts.iter().filter(joinable) {
+                solutions.push(left.iter().chain(right.iter()).copied().collect());
+                if solutions.len() == self.limit() {
+                    return solutions;
+                }
+            }
+        }
+
+        solutions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [u32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    /// Check every index set, the slow reference
+    fn brute_force(values: &[i64], target: i64, k: usize, reuse: bool) -> Vec<Vec<usize>> {
+        let index_sets = if reuse {
+            (0..values.len())
+                .combinations_with_replacement(k)
+                .collect::<Vec<_>>()
+        } else {
+            (0..values.len()).combinations(k).collect()
+        };
+
+        index_sets
+            .into_iter()
+            .filter(|set| set.iter().map(|&i| values[i]).sum::<i64>() == target)
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(KSum::new(2020, 2).solve(&EXAMPLE), vec![vec![0, 3]]);
+        assert_eq!(KSum::new(2020, 3).solve(&EXAMPLE), vec![vec![1, 2, 4]]);
+        assert_eq!(
+            KSum::new(2020, 2).with_reuse(true).solve(&[1010, 5]),
+            vec![vec![0, 0]]
+        );
+    }
+
+    #[test]
+    fn same_as_brute_force() {
+        let values = [-3, 1, 2, 2, 3, -4, 5, 5, 0, 7, 2];
+        for reuse in [false, true].iter().copied() {
+            for k in 0..=5 {
+                for target in -10..20 {
+                    let solver = KSum::new(target, k)
+                        .with_search(Search::All)
+                        .with_reuse(reuse);
+                    assert_eq!(
+                        solver.solve(&values),
+                        brute_force(&values, target, k, reuse),
+                        "k: {}, target: {}, reuse: {}",
+                        k,
+                        target,
+                        reuse
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn first_only() {
+        let values = [2, 2, 2, 2];
+        assert_eq!(KSum::new(6, 3).solve(&values).len(), 1);
+        assert_eq!(
+            KSum::new(6, 3)
+                .with_search(Search::All)
+                .solve(&values)
+                .len(),
+            4
+        );
+    }
+}
@@ -0,0 +1,151 @@
+pub mod ksum;
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Context;
+
+use common::diagnostic::{parse_lines, Diagnostic};
+use common::report::Answer;
+
+use ksum::{KSum, Number};
+
+/// Sum the entries have to add up to
+pub const TARGET: i64 = 2020;
+
+/// Entries of the expense report
+pub type Input = Vec<i64>;
+
+pub type Error = Diagnostic;
+
+/// Parse the expense report, one signed entry per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    parse_entries(input)
+}
+
+/// First two entries that sum up to 2020
+pub fn part1(input: &Input) -> Option<Entries<i64>> {
+    find_entries(input, &KSum::new(TARGET, 2)).pop()
+}
+
+/// First three entries that sum up to 2020
+pub fn part2(input: &Input) -> Option<Entries<i64>> {
+    find_entries(input, &KSum::new(TARGET, 3)).pop()
+}
+
+/// One entry of any number type per line, surrounding whitespace is ignored
+pub fn parse_entries<T>(input: &str) -> Result<Vec<T>, Diagnostic>
+where
+    T: FromStr,
+    T::Err: StdError + Send + Sync + 'static,
+{
+    parse_lines(input, |line| line.trim().parse::<T>())
+}
+
+/// Entries of all solutions found by the solver
+pub fn find_entries<T: Number>(input: &[T], solver: &KSum<T>) -> Vec<Entries<T>> {
+    solver
+        .solve(input)
+        .into_iter()
+        .map(|indices| Entries::new(input, indices))
+        .collect()
+}
+
+pub struct Day01;
+
+impl common::Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Input;
+    type Part1 = Entries<i64>;
+    type Part2 = Entries<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        part1(input).context("found no two values")
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        part2(input).context("found no three values")
+    }
+}
+
+/// Entries of the expense report that sum up to the searched value
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Entries<T> {
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Number> Entries<T> {
+    /// Entries at the given indices of input
+    pub fn new(input: &[T], indices: Vec<usize>) -> Self {
+        let values = indices.iter().map(|&i| input[i]).collect();
+        Entries { indices, values }
+    }
+
+    /// Positions in the expense report, tell apart equal values
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn sum(&self) -> i128 {
+        self.values.iter().map(|value| value.to_i128()).sum()
+    }
+
+    /// None if it does not fit into i128
+    pub fn product(&self) -> Option<i128> {
+        self.values.iter().try_fold(1_i128, |product, value| {
+            product.checked_mul(value.to_i128())
+        })
+    }
+}
+
+/// The answer is the product of the entries
+impl<T: Number> fmt::Display for Entries<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.product() {
+            Some(product) => write!(f, "{}", product),
+            None => write!(f, "overflow"),
+        }
+    }
+}
+
+impl<T: Number> Answer for Entries<T> {
+    fn extra(&self) -> Vec<(String, String)> {
+        vec![
+            ("values".to_owned(), format!("{:?}", self.values)),
+            ("indices".to_owned(), format!("{:?}", self.indices)),
+            ("sum".to_owned(), self.sum().to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day01,
+        example_1: common::example_input!("1") => { part1: 514579, part2: 241861950 },
+    }
+
+    #[test]
+    fn signed_entries() {
+        let input = parse("-5\n 2025\n1010\n1010\n").unwrap();
+        assert_eq!(part1(&input).unwrap().indices(), &[0, 1]);
+        assert_eq!(part2(&input), None);
+
+        let err = parse("1\n-\n3").unwrap_err();
+        assert_eq!(err.span().line, 2);
+        assert!(parse_entries::<u32>("-5").is_err());
+    }
+}
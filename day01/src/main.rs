@@ -1,62 +1,97 @@
-use std::fs;
+use std::time::Instant;
 
-use itertools::Itertools;
+use anyhow::bail;
+use clap::Clap;
+
+use common::report::{Part, PartResult, Report};
+use common::Solution;
+use day01::ksum::{KSum, Search};
+use day01::Day01;
+
+#[derive(Clap)]
+#[clap(
+    version = "1.0",
+    author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
+)]
+struct Opts {
+    #[clap(flatten)]
+    common: common::simple_cli::Opts,
+
+    /// Sum the entries have to add up to
+    #[clap(long, default_value = "2020", allow_hyphen_values = true)]
+    target: i64,
+
+    /// Search sets of k entries instead of two for part 1 and three for part 2
+    #[clap(long)]
+    k: Option<usize>,
+
+    /// Print every distinct set of entries instead of the first one
+    #[clap(long)]
+    all: bool,
+
+    /// Allow using the same entry more than once
+    #[clap(long)]
+    reuse: bool,
+}
 
 fn main() -> anyhow::Result<()> {
-    let options = common::simple_cli::Opts::get();
+    let options = Opts::parse();
+
+    let content = common::input::load(&options.common.source_for::<Day01>()?)?;
 
-    let content = get_contents(fs::read_to_string(options.input)?);
+    if let Some(runs) = options.common.bench {
+        let bench = common::bench::bench::<Day01>(&content, runs)?;
+        options.common.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    print_result(&content, 2020, 2, "two");
+    let report =
+        if options.target == day01::TARGET && options.k.is_none() && !options.all && !options.reuse
+        {
+            common::solution::solve::<Day01>(&content)?
+                .with_label(Part::One, "Product of two entries that sum to 2020")
+                .with_label(Part::Two, "Product of three entries that sum to 2020")
+        } else {
+            search_report(&options, &content)?
+        };
 
-    print_result(&content, 2020, 3, "three");
+    options.common.format.print(&[report])?;
 
     Ok(())
 }
 
-fn get_contents(input: String) -> Vec<usize> {
-    input
-        .lines()
-        .map(str::parse::<usize>)
-        .enumerate()
-        .map(|(count, value)| {
-            if let Ok(value) = value {
-                Some(value)
-            } else {
-                println!("Error parsing value at position {}: {:?}", count, value);
-                None
-            }
-        })
-        .filter_map(|x| x)
-        .collect()
-}
+/// One part per found set of entries, named after k
+fn search_report(options: &Opts, content: &str) -> anyhow::Result<Report> {
+    let input = Day01::parse(content)?;
+    let search = if options.all {
+        Search::All
+    } else {
+        Search::First
+    };
 
-fn get_all_summed_to_x(vec: &[usize], x: usize, len: usize) -> Vec<Vec<usize>> {
-    vec.iter()
-        .map(usize::clone)
-        .combinations(len)
-        .map(|i| (i.clone(), i.iter().sum::<usize>()))
-        .filter_map(|(i, sum)| if sum == x { Some(i) } else { None })
-        .collect()
-}
+    let mut report = Report::new(Day01::DAY);
+    for k in options.k.map_or(vec![2, 3], |k| vec![k]) {
+        let start = Instant::now();
+        let solver = KSum::new(options.target, k)
+            .with_search(search)
+            .with_reuse(options.reuse);
+        let found = day01::find_entries(&input, &solver);
+        let elapsed = start.elapsed();
 
-fn get_first_summed_to_x(vec: &[usize], x: usize, len: usize) -> Option<Vec<usize>> {
-    get_all_summed_to_x(vec, x, len).into_iter().next()
-}
+        if found.is_empty() {
+            bail!("found no {} entries that sum to {}", k, options.target);
+        }
 
-fn print_result(content: &[usize], x: usize, len: usize, len_str: &str) {
-    println!("{} items:", len_str);
-
-    match get_first_summed_to_x(content, x, len) {
-        Some(values) => println!(
-            "found {} values: {:?}\nSum: {}\nProduct: {}",
-            len_str,
-            values,
-            values.iter().sum::<usize>(),
-            values.iter().product::<usize>(),
-        ),
-        None => println!("found no {} values", len_str),
+        for (idx, entries) in found.iter().enumerate() {
+            let part = Part::Extra(format!("k={} #{}", k, idx + 1));
+            let mut result = PartResult::new(part, entries, elapsed);
+            result.label = Some(format!(
+                "Product of {} entries that sum to {}",
+                k, options.target
+            ));
+            report = report.with_part(result);
+        }
     }
 
-    println!()
+    Ok(report)
 }
@@ -0,0 +1,130 @@
+pub mod password;
+pub mod policy;
+pub mod verdict;
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use common::diagnostic::{self, Diagnostic};
+use common::report::Answer;
+
+use password::{Password, Requirement, RequirementAndPassword};
+use policy::{PasswordPolicy, Policy, PolicyAndPassword};
+use verdict::Observed;
+
+/// Requirements with the passwords they apply to, borrowed from the input
+pub type Input<'a> = Vec<(Requirement, Password<'a>)>;
+
+pub type Error = Diagnostic;
+
+/// Parse one requirement and password per line
+pub fn parse(input: &str) -> Result<Input<'_>, Error> {
+    diagnostic::parse_lines(input, |line| {
+        RequirementAndPassword::try_from(line).map(RequirementAndPassword::deconstruct)
+    })
+}
+
+/// Parse one policy and password per line, lines with a plain requirement get the default policy
+pub fn parse_policies(
+    input: &str,
+    default: impl Fn(Requirement) -> Policy,
+) -> Result<Vec<(Policy, Password<'_>)>, Error> {
+    diagnostic::parse_lines(input, |line| {
+        PolicyAndPassword::parse(line, &default).map(PolicyAndPassword::deconstruct)
+    })
+}
+
+/// Count passwords valid by the rules of part 1
+pub fn part1(input: &Input<'_>) -> PasswordCount {
+    check_passwords(input, password::check_password_part1)
+}
+
+/// Count passwords valid by the rules of part 2
+pub fn part2(input: &Input<'_>) -> PasswordCount {
+    check_passwords(input, password::check_password_part2)
+}
+
+/// Count passwords valid by the policy of their line
+pub fn check_policies<P: PasswordPolicy>(input: &[(P, Password<'_>)]) -> PasswordCount {
+    check_passwords(input, |(policy, password)| policy.check(password.as_ref()))
+}
+
+/// A diagnostic per password that violates its policy, passwords have to be slices of input
+pub fn lint<P: PasswordPolicy>(input: &str, lines: &[(P, Password<'_>)]) -> Vec<Diagnostic> {
+    lines
+        .iter()
+        .filter_map(|(policy, password)| {
+            let verdict = policy.verdict(password.as_ref());
+            let failure = verdict.failure.as_ref()?;
+            let message = format!("password violates \"{}\": {}", verdict.policy, failure);
+
+            let diagnostic =
+                Diagnostic::at_fragment(input, verdict.culprit(password.as_ref()), message);
+            Some(match verdict.observed {
+                Observed::Nothing => diagnostic,
+                observed => diagnostic.with_label(observed.to_string()),
+            })
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl common::Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Input<'a>;
+    type Part1 = PasswordCount;
+    type Part2 = PasswordCount;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+/// Count of valid and invalid passwords
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct PasswordCount {
+    pub valid: usize,
+    pub invalid: usize,
+}
+
+/// The answer is the count of valid passwords
+impl fmt::Display for PasswordCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.valid)
+    }
+}
+
+impl Answer for PasswordCount {
+    fn extra(&self) -> Vec<(String, String)> {
+        vec![("invalid".to_owned(), self.invalid.to_string())]
+    }
+}
+
+fn check_passwords<T>(content: &[T], check: impl Fn(&T) -> bool) -> PasswordCount {
+    let (valid, invalid): (Vec<_>, Vec<_>) = content.iter().map(check).partition(|v| *v);
+
+    PasswordCount {
+        valid: valid.len(),
+        invalid: invalid.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day02,
+        example_1: common::example_input!("1") => { part1: 2, part2: 1 },
+    }
+}
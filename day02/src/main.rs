@@ -1,63 +1,103 @@
-mod password;
+use anyhow::bail;
+use clap::Clap;
 
-use password::{Password, Requirement, RequirementAndPassword};
-use std::convert::TryFrom;
-use std::fs;
+use common::report::{Part, PartResult, Report};
+use common::Solution;
+use day02::password::Password;
+use day02::policy::{CountRange, Policy, Positions, Unit};
+use day02::Day02;
 
-fn main() -> anyhow::Result<()> {
-    let options = common::simple_cli::Opts::get();
+#[derive(Clap)]
+#[clap(
+    version = "1.0",
+    author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
+)]
+struct Opts {
+    #[clap(flatten)]
+    common: common::simple_cli::Opts,
+
+    /// Check every line with the policy it names, lines with a plain requirement use this one
+    #[clap(long, possible_values = &["count", "positions"])]
+    policy: Option<String>,
 
-    let input = fs::read_to_string(options.input)?;
+    /// Count and index passwords by chars (default) or graphemes
+    #[clap(long, possible_values = &["chars", "graphemes"], requires = "policy")]
+    unit: Option<Unit>,
 
-    let content = parse(&input);
+    /// List the passwords that violate their policy instead of counting them
+    #[clap(long, requires = "policy")]
+    lint: bool,
+}
 
-    println!("Part 1 rules:");
-    check_passwords(&content, password::check_password_part1);
+fn main() -> anyhow::Result<()> {
+    let options = Opts::parse();
+
+    let content = common::input::load(&options.common.source_for::<Day02>()?)?;
+
+    if let Some(runs) = options.common.bench {
+        let bench = common::bench::bench::<Day02>(&content, runs)?;
+        options.common.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    println!();
+    let report = match options.policy.as_deref() {
+        Some(default) => {
+            let input = parse_policies(default, options.unit.unwrap_or_default(), &content)?;
+            if options.lint {
+                return lint(&content, &input);
+            }
+            policy_report(&input)
+        }
+        None => common::solution::solve::<Day02>(&content)?
+            .with_label(Part::One, "Valid passwords by part 1 rules")
+            .with_label(Part::Two, "Valid passwords by part 2 rules"),
+    };
 
-    println!("Part 2 rules:");
-    check_passwords(&content, password::check_password_part2);
+    options.common.format.print(&[report])?;
 
     Ok(())
 }
 
-fn parse(input: &str) -> Vec<(Requirement, Password)> {
-    // parse each line for requirement and password
-    // label them with line number
-    // partition whether the parse succeeded or failed
-    let (succeeded, fail): (Vec<_>, Vec<_>) = input
-        .lines()
-        .map(RequirementAndPassword::try_from)
-        .enumerate()
-        .partition(|(_, val)| val.is_ok());
-
-    // ignore line number from succeeded & unwrap from Result
-    let results = succeeded
-        .into_iter()
-        .map(|(_, v)| v.unwrap().deconstruct())
-        .collect::<Vec<_>>();
+/// Policy of every line, lines with a plain requirement get the default policy
+fn parse_policies<'a>(
+    default: &str,
+    unit: Unit,
+    content: &'a str,
+) -> anyhow::Result<Vec<(Policy, Password<'a>)>> {
+    let default = |requirement| match default {
+        "positions" => Policy::Positions(Positions::new(requirement)),
+        _ => Policy::Count(CountRange::new(requirement)),
+    };
 
-    // just unwrap the failed
-    let errors = fail
+    Ok(day02::parse_policies(content, default)?
         .into_iter()
-        .map(|(c, v)| (c, v.unwrap_err()))
-        .collect::<Vec<_>>();
-
-    // print out failed parses
-    for (count, error) in errors {
-        println!("Line {}. {:#?}", count + 1, error);
-    }
+        .map(|(policy, password)| (policy.with_unit(unit), password))
+        .collect())
+}
 
-    results
+/// Single part with the passwords valid by the policy of their line
+fn policy_report(input: &[(Policy, Password)]) -> Report {
+    let part = Part::Extra("policies".to_owned());
+    Report::new(Day02::DAY)
+        .with_part(PartResult::timed(part.clone(), || {
+            day02::check_policies(input)
+        }))
+        .with_label(part, "Valid passwords by named policies")
 }
 
-fn check_passwords(
-    content: &[(Requirement, Password)],
-    check: impl Fn(&(Requirement, Password)) -> bool,
-) {
-    let (valid, invalid): (Vec<_>, Vec<_>) = content.iter().map(check).partition(|v| *v);
+/// Print every violation, fails if there is any
+fn lint(content: &str, input: &[(Policy, Password)]) -> anyhow::Result<()> {
+    let violations = day02::lint(content, input);
+    for violation in &violations {
+        println!("{}\n", violation);
+    }
 
-    println!("{} passwords invalid", invalid.len());
-    println!("{} passwords valid", valid.len());
+    if !violations.is_empty() {
+        bail!(
+            "{} of {} passwords violate their policy",
+            violations.len(),
+            input.len()
+        );
+    }
+    Ok(())
 }
@@ -1,12 +1,15 @@
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
+use crate::policy::{CountRange, PasswordPolicy, Positions};
+
 pub use error::Location;
 pub use error::RequirementAndPasswordParseError;
 pub use error::RequirementParseError;
 
 /// Wrapper for password requirements
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Requirement {
     min: usize,
     max: usize,
@@ -18,45 +21,26 @@ impl Requirement {
         Self { min, max, char }
     }
 
-    /// Check password with rules of part 1.
-    /// The given char should only be present between min and max times in the password
-    pub fn check_password_part1<T: AsRef<str>>(&self, password: T) -> bool {
-        // count all chars that equal saved char
-        let count = password
-            .as_ref()
-            .chars()
-            .map(|c| c.eq(&self.char))
-            .filter(|b| *b)
-            .count();
-
-        // check that count is between min and max
-        self.min <= count && count <= self.max
+    pub fn min(&self) -> usize {
+        self.min
     }
 
-    /// Check password with rules of part 2.
-    /// The given char should be at either min position or max position, but not both
-    pub fn check_password_part2<T: AsRef<str>>(&self, password: T) -> bool {
-        let pwd = password.as_ref();
-
-        assert!(self.min <= self.max);
-
-        // passwords 1 indexed (not 0) -> compensate
-        let min = self.min - 1;
-        let max = self.max - 1;
-        let diff = max - min - 1;
+    pub fn max(&self) -> usize {
+        self.max
+    }
 
-        // check length before access
-        if pwd.len() <= min || pwd.len() <= max {
-            return false;
-        }
+    pub fn char(&self) -> char {
+        self.char
+    }
 
-        // get chars from password
-        let mut pwd_iter = pwd.chars();
-        let min_char = pwd_iter.nth(min).unwrap();
-        let max_char = pwd_iter.nth(diff).unwrap();
+    /// Check password with rules of part 1, see policy::CountRange
+    pub fn check_password_part1<T: AsRef<str>>(&self, password: T) -> bool {
+        CountRange::new(*self).check(password.as_ref())
+    }
 
-        // xor check results, because just one is allowed to be equal to check char
-        (min_char == self.char) ^ (max_char == self.char)
+    /// Check password with rules of part 2, see policy::Positions
+    pub fn check_password_part2<T: AsRef<str>>(&self, password: T) -> bool {
+        Positions::new(*self).check(password.as_ref())
     }
 }
 
@@ -125,6 +109,13 @@ impl FromStr for Requirement {
     }
 }
 
+/// Format: MIN-MAX CHAR
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.min, self.max, self.char)
+    }
+}
+
 /// Just a wrapper for a borrowed string
 #[derive(Default, Debug)]
 pub struct Password<'a> {
@@ -219,6 +210,7 @@ pub mod error {
         HyphenSplitBetweenNumerals,
         MoreThanOneChar,
         SplitBetweenRequirementAndPassword,
+        SpaceSplitBetweenPolicyAndArguments,
     }
 
     impl fmt::Display for Location {
@@ -234,6 +226,9 @@ pub mod error {
                 Location::SplitBetweenRequirementAndPassword => {
                     f.write_str("\": \" split between requirement and password")
                 }
+                Location::SpaceSplitBetweenPolicyAndArguments => {
+                    f.write_str("space split between policy name and arguments")
+                }
             }
         }
     }
@@ -247,3 +242,26 @@ pub mod error {
         RequirementParseError(#[from] RequirementParseError),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // a space would split the requirement
+    fn requirement() -> impl Strategy<Value = Requirement> {
+        (
+            any::<usize>(),
+            any::<usize>(),
+            any::<char>().prop_filter("space", |c| *c != ' '),
+        )
+            .prop_map(|(min, max, char)| Requirement::new(min, max, char))
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trip(requirement in requirement()) {
+            prop_assert_eq!(requirement.to_string().parse::<Requirement>().unwrap(), requirement);
+        }
+    }
+}
@@ -0,0 +1,687 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::password::{Location, Password, Requirement};
+use crate::verdict::{Failure, Observed, Verdict};
+
+pub use error::PolicyAndPasswordParseError;
+pub use error::PolicyParseError;
+
+/// Rule a password has to follow
+///
+/// Display describes the policy in the format it is named with in the input
+pub trait PasswordPolicy: fmt::Display {
+    /// What the policy found in the password and why it is invalid
+    fn verdict(&self, password: &str) -> Verdict;
+
+    fn check(&self, password: &str) -> bool {
+        self.verdict(password).is_valid()
+    }
+}
+
+impl<P: PasswordPolicy + ?Sized> PasswordPolicy for Box<P> {
+    fn verdict(&self, password: &str) -> Verdict {
+        (**self).verdict(password)
+    }
+}
+
+// Unit
+//##################
+
+/// What counts and positions in a password refer to
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Unit {
+    /// Unicode scalar values
+    #[default]
+    Chars,
+    /// Extended grapheme clusters, what a reader sees as one character
+    Graphemes,
+}
+
+impl Unit {
+    /// Split the password into its units, a char becomes a slice of its own
+    pub fn split(self, password: &str) -> Vec<&str> {
+        match self {
+            Unit::Chars => password
+                .char_indices()
+                .map(|(idx, c)| &password[idx..idx + c.len_utf8()])
+                .collect(),
+            Unit::Graphemes => password.graphemes(true).collect(),
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = PolicyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chars" => Ok(Unit::Chars),
+            "graphemes" => Ok(Unit::Graphemes),
+            unit => Err(PolicyParseError::UnknownUnit(unit.to_owned())),
+        }
+    }
+}
+
+/// True if the unit consists of just that char, a grapheme with combining marks never does
+fn is_char(unit: &str, char: char) -> bool {
+    let mut chars = unit.chars();
+    chars.next() == Some(char) && chars.next().is_none()
+}
+
+/// Bytes of password covered by part, which has to be a slice of it
+fn range_in(password: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - password.as_ptr() as usize;
+    start..start + part.len()
+}
+
+//##################
+
+// Built-in policies
+//##################
+
+/// The char has to be present between min and max times, rules of part 1
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CountRange {
+    requirement: Requirement,
+    unit: Unit,
+}
+
+impl CountRange {
+    pub fn new(requirement: Requirement) -> Self {
+        Self {
+            requirement,
+            unit: Unit::default(),
+        }
+    }
+
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl PasswordPolicy for CountRange {
+    fn verdict(&self, password: &str) -> Verdict {
+        let Self { requirement, unit } = self;
+        let count = unit
+            .split(password)
+            .into_iter()
+            .filter(|u| is_char(u, requirement.char()))
+            .count();
+
+        let verdict = Verdict::new(self, Observed::Count(count));
+        if requirement.min() <= count && count <= requirement.max() {
+            verdict
+        } else {
+            verdict.failed(Failure::CountOutOfRange {
+                min: requirement.min(),
+                max: requirement.max(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for CountRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "count {}", self.requirement)
+    }
+}
+
+/// The char has to be at either the min or max position, but not both, rules of part 2
+///
+/// Positions start at 1, a password too short for either of them is invalid
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Positions {
+    requirement: Requirement,
+    unit: Unit,
+}
+
+impl Positions {
+    pub fn new(requirement: Requirement) -> Self {
+        Self {
+            requirement,
+            unit: Unit::default(),
+        }
+    }
+
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl PasswordPolicy for Positions {
+    fn verdict(&self, password: &str) -> Verdict {
+        let Self { requirement, unit } = self;
+        let units = unit.split(password);
+
+        // passwords 1 indexed (not 0) -> compensate, position 0 does not exist
+        let at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|idx| units.get(idx).copied())
+        };
+        let observed = [requirement.min(), requirement.max()]
+            .iter()
+            .map(|&position| (position, at(position).map(str::to_owned)))
+            .collect();
+        let verdict = Verdict::new(self, Observed::Positions(observed));
+
+        let (min, max) = match (at(requirement.min()), at(requirement.max())) {
+            (Some(min), Some(max)) => (min, max),
+            _ => {
+                return verdict.failed(Failure::TooShort {
+                    length: units.len(),
+                })
+            }
+        };
+        let culprit = range_in(password, min).start..range_in(password, max).end;
+
+        // xor check results, because just one is allowed to be equal to check char
+        match (
+            is_char(min, requirement.char()),
+            is_char(max, requirement.char()),
+        ) {
+            (true, true) => verdict.failed(Failure::AtBothPositions).at(culprit),
+            (false, false) => verdict.failed(Failure::AtNeitherPosition).at(culprit),
+            _ => verdict,
+        }
+    }
+}
+
+impl fmt::Display for Positions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "positions {}", self.requirement)
+    }
+}
+
+/// Every char of the password has to be in the class, written like in a regex: [a-z0-9_]
+///
+/// A leading ^ negates the class, a backslash escapes the next char
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CharClass {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    pub fn new(negated: bool, ranges: Vec<(char, char)>) -> Self {
+        Self { negated, ranges }
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        let in_ranges = self
+            .ranges
+            .iter()
+            .any(|&(start, end)| start <= c && c <= end);
+        in_ranges != self.negated
+    }
+}
+
+impl PasswordPolicy for CharClass {
+    fn verdict(&self, password: &str) -> Verdict {
+        let mut outside = password.char_indices().filter(|&(_, c)| !self.contains(c));
+        match outside.next() {
+            Some((idx, first)) => {
+                let chars = std::iter::once(first)
+                    .chain(outside.map(|(_, c)| c))
+                    .collect();
+                Verdict::new(self, Observed::Outside(chars))
+                    .failed(Failure::OutsideClass)
+                    .at(idx..idx + first.len_utf8())
+            }
+            None => Verdict::new(self, Observed::Outside(Vec::new())),
+        }
+    }
+}
+
+impl FromStr for CharClass {
+    type Err = PolicyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(PolicyParseError::InvalidClass("not enclosed in brackets"))?;
+        let (negated, inner) = match inner.strip_prefix('^') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+
+        // resolve escapes first, so ranges can use escaped chars as bounds
+        let mut chars = Vec::new();
+        let mut iter = inner.chars();
+        while let Some(c) = iter.next() {
+            match c {
+                '\\' => match iter.next() {
+                    Some(escaped) => chars.push((escaped, true)),
+                    None => return Err(PolicyParseError::InvalidClass("trailing backslash")),
+                },
+                ']' => return Err(PolicyParseError::InvalidClass("unescaped ]")),
+                c => chars.push((c, false)),
+            }
+        }
+
+        let mut ranges = Vec::new();
+        let mut index = 0;
+        while index < chars.len() {
+            let start = chars[index].0;
+            match chars.get(index + 1..index + 3) {
+                Some([('-', false), (end, _)]) => {
+                    if *end < start {
+                        return Err(PolicyParseError::InvalidClass("range out of order"));
+                    }
+                    ranges.push((start, *end));
+                    index += 3;
+                }
+                _ => {
+                    ranges.push((start, start));
+                    index += 1;
+                }
+            }
+        }
+
+        Ok(CharClass::new(negated, ranges))
+    }
+}
+
+/// Format: class [^a-z\]], escapes chars with a special meaning
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let escaped = |c: char| match c {
+            '\\' | ']' | '-' | '^' => format!("\\{}", c),
+            c => c.to_string(),
+        };
+
+        f.write_str("class [")?;
+        if self.negated {
+            f.write_str("^")?;
+        }
+        for &(start, end) in &self.ranges {
+            if start == end {
+                f.write_str(&escaped(start))?;
+            } else {
+                write!(f, "{}-{}", escaped(start), escaped(end))?;
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+/// The password has to consist of at least min different chars
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MinDistinct {
+    min: usize,
+    unit: Unit,
+}
+
+impl MinDistinct {
+    pub fn new(min: usize) -> Self {
+        Self {
+            min,
+            unit: Unit::default(),
+        }
+    }
+
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl PasswordPolicy for MinDistinct {
+    fn verdict(&self, password: &str) -> Verdict {
+        let mut units = self.unit.split(password);
+        units.sort_unstable();
+        units.dedup();
+
+        let verdict = Verdict::new(self, Observed::Distinct(units.len()));
+        if units.len() >= self.min {
+            verdict
+        } else {
+            verdict.failed(Failure::TooFewDistinct { min: self.min })
+        }
+    }
+}
+
+impl fmt::Display for MinDistinct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "distinct {}", self.min)
+    }
+}
+
+/// None of the substrings may be part of the password
+///
+/// Substrings are separated by ',' in the input, so they can not contain one
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Forbidden(pub Vec<String>);
+
+impl PasswordPolicy for Forbidden {
+    fn verdict(&self, password: &str) -> Verdict {
+        let found = self
+            .0
+            .iter()
+            .filter_map(|forbidden| Some((password.find(forbidden.as_str())?, forbidden)))
+            .collect::<Vec<_>>();
+
+        let verdict = Verdict::new(
+            self,
+            Observed::Found(found.iter().map(|(_, s)| s.to_string()).collect()),
+        );
+        match found.iter().min() {
+            Some(&(idx, first)) => verdict
+                .failed(Failure::ContainsForbidden)
+                .at(idx..idx + first.len()),
+            None => verdict,
+        }
+    }
+}
+
+/// Format: forbidden SUBSTRING,SUBSTRING
+impl fmt::Display for Forbidden {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "forbidden {}", self.0.join(","))
+    }
+}
+
+//##################
+
+/// Any of the built-in policies, named in the input
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Policy {
+    Count(CountRange),
+    Positions(Positions),
+    Class(CharClass),
+    Distinct(MinDistinct),
+    Forbidden(Forbidden),
+}
+
+impl Policy {
+    /// Count in and index by the unit, classes and substrings work the same in all units
+    pub fn with_unit(self, unit: Unit) -> Self {
+        match self {
+            Policy::Count(policy) => Policy::Count(policy.with_unit(unit)),
+            Policy::Positions(policy) => Policy::Positions(policy.with_unit(unit)),
+            Policy::Distinct(policy) => Policy::Distinct(policy.with_unit(unit)),
+            policy => policy,
+        }
+    }
+}
+
+impl PasswordPolicy for Policy {
+    fn verdict(&self, password: &str) -> Verdict {
+        match self {
+            Policy::Count(policy) => policy.verdict(password),
+            Policy::Positions(policy) => policy.verdict(password),
+            Policy::Class(policy) => policy.verdict(password),
+            Policy::Distinct(policy) => policy.verdict(password),
+            Policy::Forbidden(policy) => policy.verdict(password),
+        }
+    }
+}
+
+/// Parse a named policy
+impl FromStr for Policy {
+    type Err = PolicyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Policy format:
+        // NAME ARGUMENTS
+
+        // count MIN-MAX CHAR
+        // positions MIN-MAX CHAR
+        // class [CLASS]
+        // distinct MIN
+        // forbidden SUBSTRING,SUBSTRING
+        // SUBSTRING can not contain ','
+        let (name, arguments) = s.split_once(' ').ok_or(PolicyParseError::Malformed(
+            Location::SpaceSplitBetweenPolicyAndArguments,
+        ))?;
+
+        match name {
+            "count" => Ok(Policy::Count(CountRange::new(arguments.parse()?))),
+            "positions" => Ok(Policy::Positions(Positions::new(arguments.parse()?))),
+            "class" => Ok(Policy::Class(arguments.parse()?)),
+            "distinct" => arguments
+                .parse()
+                .map(|min| Policy::Distinct(MinDistinct::new(min)))
+                .map_err(PolicyParseError::DistinctParseError),
+            "forbidden" => {
+                let substrings = arguments.split(',').map(str::to_owned).collect::<Vec<_>>();
+                if substrings.iter().any(String::is_empty) {
+                    return Err(PolicyParseError::EmptySubstring);
+                }
+                Ok(Policy::Forbidden(Forbidden(substrings)))
+            }
+            name => Err(PolicyParseError::UnknownPolicy(name.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Policy::Count(policy) => policy.fmt(f),
+            Policy::Positions(policy) => policy.fmt(f),
+            Policy::Class(policy) => policy.fmt(f),
+            Policy::Distinct(policy) => policy.fmt(f),
+            Policy::Forbidden(policy) => policy.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PolicyAndPassword<'a> {
+    policy: Policy,
+    password: Password<'a>,
+}
+
+impl<'a> PolicyAndPassword<'a> {
+    pub fn new(policy: Policy, password: Password<'a>) -> Self {
+        Self { policy, password }
+    }
+
+    /// Parse a line with a named policy, lines with a plain requirement get the default policy
+    pub fn parse(
+        s: &'a str,
+        default: impl Fn(Requirement) -> Policy,
+    ) -> Result<Self, PolicyAndPasswordParseError> {
+        // Policy and Password format:
+        // POLICY: PASSWORD
+        // MIN-MAX CHAR: PASSWORD
+
+        // split at the last ": ", policies may contain it, passwords may not
+        let (policy, password) =
+            s.rsplit_once(": ")
+                .ok_or(PolicyAndPasswordParseError::Malformed(
+                    Location::SplitBetweenRequirementAndPassword,
+                ))?;
+
+        // policy names never start with a digit, requirements always do
+        let policy = if policy.starts_with(|c: char| c.is_ascii_digit()) {
+            default(
+                policy
+                    .parse::<Requirement>()
+                    .map_err(PolicyParseError::from)?,
+            )
+        } else {
+            policy.parse::<Policy>()?
+        };
+
+        Ok(PolicyAndPassword::new(policy, password.into()))
+    }
+
+    pub fn deconstruct(self) -> (Policy, Password<'a>) {
+        (self.policy, self.password)
+    }
+}
+
+pub mod error {
+    use std::num;
+    use thiserror::Error;
+
+    use crate::password::{Location, RequirementParseError};
+
+    #[derive(Error, Debug)]
+    pub enum PolicyParseError {
+        #[error("input string malformed: {0}")]
+        Malformed(Location),
+
+        #[error("unknown policy: {0}")]
+        UnknownPolicy(String),
+
+        #[error("unknown unit: {0}, expected chars or graphemes")]
+        UnknownUnit(String),
+
+        #[error("failed parsing requirement: {0}")]
+        RequirementParseError(#[from] RequirementParseError),
+
+        #[error("invalid char class: {0}")]
+        InvalidClass(&'static str),
+
+        #[error("failed to parse min distinct chars: {0}")]
+        DistinctParseError(num::ParseIntError),
+
+        #[error("forbidden substrings may not be empty")]
+        EmptySubstring,
+    }
+
+    #[derive(Error, Debug)]
+    pub enum PolicyAndPasswordParseError {
+        #[error("input string malformed: {0}")]
+        Malformed(Location),
+
+        #[error("failed parsing policy: {0}")]
+        PolicyParseError(#[from] PolicyParseError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(policy: &str, password: &str) -> bool {
+        policy.parse::<Policy>().unwrap().check(password)
+    }
+
+    #[test]
+    fn built_in() {
+        assert!(check("count 1-3 a", "abcde"));
+        assert!(!check("positions 2-9 c", "ccccccccc"));
+        assert!(check("class [a-z0-9_]", "pass_w0rd"));
+        assert!(!check("class [a-z0-9_]", "Password"));
+        assert!(check("class [^\\]\\-]", "a^b"));
+        assert!(!check("class [^\\]\\-]", "a-b"));
+        assert!(check("distinct 4", "abcdd"));
+        assert!(!check("distinct 5", "abcdd"));
+        assert!(check("forbidden 1234,password", "hunter2"));
+        assert!(!check("forbidden 1234,password", "my_password"));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for policy in &[
+            "count 1-3 a",
+            "positions 1-3 b",
+            "class [^a-z\\]\\\\\\-]",
+            "distinct 7",
+            "forbidden abc,123",
+        ] {
+            assert_eq!(policy.parse::<Policy>().unwrap().to_string(), *policy);
+        }
+    }
+
+    #[test]
+    fn named_per_line() {
+        let line = |s| PolicyAndPassword::parse(s, |r| Policy::Count(CountRange::new(r)));
+
+        let (policy, _) = line("1-3 a: abcde").unwrap().deconstruct();
+        assert_eq!(policy, "count 1-3 a".parse().unwrap());
+        let (policy, password) = line("distinct 3: aab").unwrap().deconstruct();
+        assert!(!policy.check(password.as_ref()));
+
+        assert!(line("length 3: abc").is_err());
+        assert!(line("class [a-: abc").is_err());
+        assert!(line("forbidden a,,b: abc").is_err());
+
+        let (policy, password) = line("class [: a-z]: my pw").unwrap().deconstruct();
+        assert!(policy.check(password.as_ref()));
+        assert!(!policy.check("my_pw"));
+        let (policy, password) = line("forbidden a: b: pw").unwrap().deconstruct();
+        assert_eq!(
+            policy,
+            Policy::Forbidden(Forbidden(vec!["a: b".to_owned()]))
+        );
+        assert_eq!(password.as_ref(), "pw");
+    }
+
+    #[test]
+    fn verdicts() {
+        let verdict = |policy: &str, password| policy.parse::<Policy>().unwrap().verdict(password);
+
+        let count = verdict("count 1-3 b", "cdefg");
+        assert_eq!(count.observed, Observed::Count(0));
+        assert_eq!(
+            count.failure,
+            Some(Failure::CountOutOfRange { min: 1, max: 3 })
+        );
+        assert_eq!(
+            count.to_string(),
+            "count 1-3 b: count not between 1 and 3 (found 0)"
+        );
+
+        let positions = verdict("positions 2-9 c", "ccccccccc");
+        assert_eq!(positions.failure, Some(Failure::AtBothPositions));
+        assert_eq!(positions.culprit("ccccccccc"), "cccccccc");
+        assert_eq!(
+            verdict("positions 2-9 c", "cc").failure,
+            Some(Failure::TooShort { length: 2 })
+        );
+
+        let class = verdict("class [a-z]", "pass");
+        assert_eq!(class.observed, Observed::Outside(vec![]));
+        let class = verdict("class [a-z]", "Päß");
+        assert_eq!(class.observed, Observed::Outside(vec!['P', 'ä', 'ß']));
+        assert_eq!(class.culprit("Päß"), "P");
+
+        let forbidden = verdict("forbidden word,pass", "password");
+        assert_eq!(
+            forbidden.observed,
+            Observed::Found(vec!["word".to_owned(), "pass".to_owned()])
+        );
+        assert_eq!(forbidden.culprit("password"), "pass");
+        assert!(verdict("distinct 3", "abc").is_valid());
+    }
+
+    #[test]
+    fn non_ascii() {
+        let requirement = |s: &str| s.parse::<Requirement>().unwrap();
+        let positions = |s, unit| Positions::new(requirement(s)).with_unit(unit);
+        let count = |s, unit| CountRange::new(requirement(s)).with_unit(unit);
+
+        // 3 bytes, but only 2 chars
+        assert!(!positions("2-3 b", Unit::Chars).check("äb"));
+        assert!(positions("1-2 ß", Unit::Chars).check("ßtraße"));
+        assert!(count("2-2 ß", Unit::Chars).check("ßtraße"));
+        assert!(positions("1-3 日", Unit::Chars).check("日本語"));
+
+        // e and a combining acute accent are one grapheme
+        let password = "cafe\u{301}s";
+        assert!(positions("5-6 s", Unit::Chars).check(password));
+        assert!(!positions("5-6 s", Unit::Graphemes).check(password));
+        assert!(positions("1-5 s", Unit::Graphemes).check(password));
+        assert!(count("1-1 e", Unit::Chars).check(password));
+        assert!(count("0-0 e", Unit::Graphemes).check(password));
+
+        assert!(MinDistinct::new(6).check(password));
+        assert!(!MinDistinct::new(6)
+            .with_unit(Unit::Graphemes)
+            .check(password));
+
+        assert!(!positions("0-1 c", Unit::Chars).check(password));
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::ops::Range;
+
+/// Outcome of checking a password against a policy
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Verdict {
+    /// The applied policy, formatted like in the input
+    pub policy: String,
+    pub observed: Observed,
+    /// Why the password is invalid, None if it is valid
+    pub failure: Option<Failure>,
+    /// Bytes of the password the failure is about, the whole password if None
+    pub culprit: Option<Range<usize>>,
+}
+
+impl Verdict {
+    /// Valid verdict of policy
+    pub fn new(policy: &impl fmt::Display, observed: Observed) -> Self {
+        Self {
+            policy: policy.to_string(),
+            observed,
+            failure: None,
+            culprit: None,
+        }
+    }
+
+    pub fn failed(mut self, failure: Failure) -> Self {
+        self.failure = Some(failure);
+        self
+    }
+
+    pub fn at(mut self, culprit: Range<usize>) -> Self {
+        self.culprit = Some(culprit);
+        self
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// The part of password the failure is about
+    pub fn culprit<'a>(&self, password: &'a str) -> &'a str {
+        self.culprit
+            .clone()
+            .and_then(|culprit| password.get(culprit))
+            .unwrap_or(password)
+    }
+}
+
+/// Format: POLICY: FAILURE (OBSERVED)
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.failure {
+            Some(failure) => write!(f, "{}: {}", self.policy, failure)?,
+            None => write!(f, "{}: valid", self.policy)?,
+        }
+        match &self.observed {
+            Observed::Nothing => Ok(()),
+            observed => write!(f, " ({})", observed),
+        }
+    }
+}
+
+/// What a policy found in the password
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Observed {
+    /// How often the char is present
+    Count(usize),
+    /// Char or grapheme at each position, None if the password is too short
+    Positions(Vec<(usize, Option<String>)>),
+    /// Chars that are not in the class
+    Outside(Vec<char>),
+    /// How many different chars or graphemes there are
+    Distinct(usize),
+    /// Forbidden substrings that are part of the password
+    Found(Vec<String>),
+    /// For policies without further details
+    Nothing,
+}
+
+impl fmt::Display for Observed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Observed::Count(count) => write!(f, "found {}", count),
+            Observed::Positions(positions) => {
+                let positions = positions
+                    .iter()
+                    .map(|(position, found)| match found {
+                        Some(found) => format!("{}: {:?}", position, found),
+                        None => format!("{}: none", position),
+                    })
+                    .collect::<Vec<_>>();
+                f.write_str(&positions.join(", "))
+            }
+            Observed::Outside(chars) => write!(f, "outside: {:?}", chars),
+            Observed::Distinct(distinct) => write!(f, "{} distinct", distinct),
+            Observed::Found(substrings) => write!(f, "found: {:?}", substrings),
+            Observed::Nothing => Ok(()),
+        }
+    }
+}
+
+/// Why a password violates a policy
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Failure {
+    CountOutOfRange {
+        min: usize,
+        max: usize,
+    },
+    AtBothPositions,
+    AtNeitherPosition,
+    TooShort {
+        length: usize,
+    },
+    OutsideClass,
+    TooFewDistinct {
+        min: usize,
+    },
+    ContainsForbidden,
+    /// For policies with reasons of their own
+    Other(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::CountOutOfRange { min, max } => {
+                write!(f, "count not between {} and {}", min, max)
+            }
+            Failure::AtBothPositions => f.write_str("char at both positions"),
+            Failure::AtNeitherPosition => f.write_str("char at neither position"),
+            Failure::TooShort { length } => {
+                write!(f, "password too short for the positions, length {}", length)
+            }
+            Failure::OutsideClass => f.write_str("chars outside of the class"),
+            Failure::TooFewDistinct { min } => write!(f, "less than {} distinct", min),
+            Failure::ContainsForbidden => f.write_str("contains forbidden substring"),
+            Failure::Other(reason) => f.write_str(reason),
+        }
+    }
+}
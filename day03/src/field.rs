@@ -1,7 +1,10 @@
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
-use reduce::Reduce;
+use common::{Grid, Point};
+
+use crate::slope::Slope;
 
 pub use error::FieldParseError;
 pub use error::FieldStateParseError;
@@ -10,31 +13,61 @@ pub use error::FieldStateParseError;
 //##################
 
 #[derive(Default, Debug, Clone)]
-pub struct Field(Vec<Vec<FieldState>>);
+pub struct Field(Grid<FieldState>);
 
 impl Field {
-    fn new(content: Vec<Vec<FieldState>>) -> Self {
-        Self(content)
-    }
-
-    pub fn get_field(&self, x: usize, y: usize) -> FieldState {
+    pub fn get_field(&self, x: isize, y: usize) -> FieldState {
         // vertical
-        assert!(y < self.0.len());
-        let line = &self.0[y];
+        assert!(y < self.0.height());
 
         // horizontal
-        // endlessly repeating
-        let read_x = x % line.len();
-        line[read_x]
+        // endlessly repeating, also to the left
+        *self.0.get_wrapping(x, y as isize)
     }
 
-    pub fn is_tree_at(&self, x: usize, y: usize) -> bool {
+    pub fn is_tree_at(&self, x: isize, y: usize) -> bool {
         self.get_field(x, y) == FieldState::Tree
     }
 
+    /// Positions reached from the top left corner until the next step would leave the bottom
+    ///
+    /// The start is not part of the path, x is not wrapped
+    pub fn path(&self, slope: Slope) -> Vec<Point<isize>> {
+        let steps = self.len().saturating_sub(1) / slope.down;
+
+        (1..=steps)
+            .map(|step| Point {
+                x: slope.right * step as isize,
+                y: (slope.down * step) as isize,
+            })
+            .collect()
+    }
+
+    /// The width of the pattern that repeats to the right
+    pub fn width(&self) -> usize {
+        self.0.width()
+    }
+
     /// The distance from top to bottom
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.0.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.height() == 0
+    }
+}
+
+/// The map as in the input, without repetition
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.0.rows() {
+            for state in row {
+                write!(f, "{}", state)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
@@ -42,42 +75,8 @@ impl FromStr for Field {
     type Err = FieldParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Convert input into Vec<Vec<FieldState>>
-        // Two-dimensional field of states
-        let values = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(FieldState::try_from)
-                    .collect::<Result<Vec<_>, _>>()
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        // Check that every line equal length
-        // Count length, wrap in option, and enumerate them
-        // Check if length is different from line before,
-        //  if yes, propagate None and line number to end
-        //  if no, continue with comparison
-        let result = values
-            .iter()
-            .map(|v| Option::Some(v.len()))
-            .enumerate()
-            .reduce(|old, new| match (old, new) {
-                // propagate none
-                ((x, None), _) => (x, None),
-                // last one is none. should never happen?
-                ((x, _), (_, None)) => (x, None),
-                // new one has different length than old -> pass the line number of error
-                ((_, Some(old)), (x, Some(new))) if old != new => (x, None),
-                // length should be equal -> pass new one
-                (_, (x, y)) => (x, y),
-            });
-        // return error from length check if applicable
-        if let Some((line, None)) = result {
-            return Err(FieldParseError::LineDifferentLength(line));
-        }
-
-        Ok(Self::new(values))
+        // Two-dimensional field of states, every line needs the same length
+        Ok(Self(s.parse()?))
     }
 }
 
@@ -91,6 +90,15 @@ pub enum FieldState {
     Tree,
 }
 
+impl fmt::Display for FieldState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldState::Empty => write!(f, "."),
+            FieldState::Tree => write!(f, "#"),
+        }
+    }
+}
+
 impl TryFrom<char> for FieldState {
     type Error = FieldStateParseError;
 
@@ -108,13 +116,9 @@ impl TryFrom<char> for FieldState {
 pub mod error {
     use thiserror::Error;
 
-    #[derive(Error, Debug)]
-    pub enum FieldParseError {
-        #[error("failed to parse state: {0}")]
-        FailedParsingFieldState(#[from] FieldStateParseError),
-        #[error("line {0} other length than the ones before")]
-        LineDifferentLength(usize),
-    }
+    use common::grid::error::ParseGridError;
+
+    pub type FieldParseError = ParseGridError<FieldStateParseError>;
 
     #[derive(Error, Debug)]
     pub enum FieldStateParseError {
@@ -0,0 +1,204 @@
+pub mod field;
+pub mod render;
+pub mod slope;
+
+use std::cmp::Reverse;
+use std::fmt;
+
+use common::diagnostic::Diagnostic;
+use common::report::Answer;
+
+use common::Point;
+
+use field::Field;
+use slope::{Goal, Slope};
+
+/// Slopes to check for part 2
+pub const SLOPES: [Slope; 5] = [
+    Slope::new(1, 1),
+    Slope::new(3, 1),
+    Slope::new(5, 1),
+    Slope::new(7, 1),
+    Slope::new(1, 2),
+];
+
+/// The map of open squares and trees
+pub type Input = Field;
+
+pub type Error = Diagnostic;
+
+/// Parse the map, one row per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    input
+        .parse::<Field>()
+        .map_err(|err| err.into_diagnostic(input))
+}
+
+/// Trees hit on the slope right 3, down 1
+pub fn part1(input: &Input) -> usize {
+    traverse_slope(input, Slope::new(3, 1))
+}
+
+/// Trees hit on all slopes to check
+pub fn part2(input: &Input) -> SlopeProduct {
+    slope_product(input, &SLOPES)
+}
+
+/// Trees hit on each of the slopes
+pub fn slope_product(input: &Input, slopes: &[Slope]) -> SlopeProduct {
+    let trees_hit = slopes
+        .iter()
+        .map(|&slope| (slope, traverse_slope(input, slope)))
+        .collect();
+
+    SlopeProduct { trees_hit }
+}
+
+/// Trees hit on the slope from the top left to the bottom
+pub fn traverse_slope(field: &Field, slope: Slope) -> usize {
+    field
+        .path(slope)
+        .into_iter()
+        .filter(|pos| field.is_tree_at(pos.x, pos.y as usize))
+        .count()
+}
+
+/// Slope within bound hitting the fewest or most trees, the first found on ties
+///
+/// See Slope::all_within for the order
+pub fn best_slope(field: &Field, bound: usize, goal: Goal) -> Option<Traversal> {
+    let trees_hit = Slope::all_within(bound).map(|slope| (slope, traverse_slope(field, slope)));
+    // min_by_key returns the first on ties, max_by_key the last
+    let (slope, trees) = match goal {
+        Goal::Fewest => trees_hit.min_by_key(|&(_, trees)| trees),
+        Goal::Most => trees_hit.min_by_key(|&(_, trees)| Reverse(trees)),
+    }?;
+
+    Some(Traversal::new(slope, trees))
+}
+
+pub struct Day03;
+
+impl common::Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = SlopeProduct;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+/// Trees hit on multiple slopes
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SlopeProduct {
+    /// Slope with trees hit on it
+    pub trees_hit: Vec<(Slope, usize)>,
+}
+
+impl SlopeProduct {
+    pub fn product(&self) -> usize {
+        self.trees_hit.iter().map(|(_, trees)| trees).product()
+    }
+}
+
+/// The answer is the product of all trees hit
+impl fmt::Display for SlopeProduct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.product())
+    }
+}
+
+/// Trees hit per slope
+impl Answer for SlopeProduct {
+    fn extra(&self) -> Vec<(String, String)> {
+        self.trees_hit
+            .iter()
+            .map(|(slope, trees)| (slope.to_string(), trees.to_string()))
+            .collect()
+    }
+}
+
+/// Trees hit on a single slope
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Traversal {
+    pub slope: Slope,
+    pub trees: usize,
+    /// Positions reached, only listed in the answer if present
+    pub path: Option<Vec<Point<isize>>>,
+}
+
+impl Traversal {
+    pub fn new(slope: Slope, trees: usize) -> Self {
+        Self {
+            slope,
+            trees,
+            path: None,
+        }
+    }
+
+    pub fn with_path(mut self, field: &Field) -> Self {
+        self.path = Some(field.path(self.slope));
+        self
+    }
+}
+
+/// The answer is the count of trees hit
+impl fmt::Display for Traversal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.trees)
+    }
+}
+
+/// Slope and path as RIGHT,DOWN positions
+impl Answer for Traversal {
+    fn extra(&self) -> Vec<(String, String)> {
+        let mut extra = vec![("slope".to_owned(), self.slope.to_string())];
+        if let Some(path) = &self.path {
+            let path = path
+                .iter()
+                .map(|pos| format!("{},{}", pos.x, pos.y))
+                .collect::<Vec<_>>();
+            extra.push(("path".to_owned(), path.join(" ")));
+        }
+        extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day03,
+        example_1: common::example_input!("1") => { part1: 7, part2: 336 },
+    }
+
+    #[test]
+    fn slopes() {
+        let field = parse(common::example_input!("1")).unwrap();
+
+        // left wraps around just like right
+        assert_eq!(traverse_slope(&field, Slope::new(-3, 1)), 3);
+        assert_eq!(traverse_slope(&field, Slope::new(-2, 2)), 4);
+        assert_eq!(
+            field.path(Slope::new(-1, 4)),
+            vec![Point { x: -1, y: 4 }, Point { x: -2, y: 8 }]
+        );
+
+        let fewest = best_slope(&field, 3, Goal::Fewest).unwrap();
+        assert_eq!((fewest.slope, fewest.trees), (Slope::new(1, 3), 0));
+        let most = best_slope(&field, 3, Goal::Most).unwrap();
+        assert_eq!((most.slope, most.trees), (Slope::new(3, 1), 7));
+    }
+}
@@ -1,61 +1,135 @@
-mod field;
-
-use std::fs;
-
-use field::Field;
+use std::io::{self, IsTerminal};
+
+use anyhow::Context;
+use clap::Clap;
+
+use common::report::{Part, PartResult, Report};
+use common::Solution;
+use day03::field::Field;
+use day03::render::Renderer;
+use day03::slope::{Goal, Slope};
+use day03::{Day03, Traversal};
+
+#[derive(Clap)]
+#[clap(
+    version = "1.0",
+    author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
+)]
+struct Opts {
+    #[clap(flatten)]
+    common: common::simple_cli::Opts,
+
+    /// Count trees on this slope as RIGHT,DOWN, repeatable. Defaults to the slopes of part 2
+    #[clap(
+        long = "slope",
+        value_name = "RIGHT,DOWN",
+        number_of_values = 1,
+        allow_hyphen_values = true
+    )]
+    slopes: Vec<Slope>,
+
+    /// Search the slope hitting the fewest or most trees
+    #[clap(long, possible_values = &["fewest", "most"])]
+    best: Option<Goal>,
+
+    /// Search slopes going at most N right, left or down
+    #[clap(long, value_name = "N", default_value = "10")]
+    bound: usize,
+
+    /// List the positions reached on each slope
+    #[clap(long)]
+    path: bool,
+
+    /// Draw the paths over the map instead of counting, colored when writing to a terminal
+    #[clap(long)]
+    render: bool,
+}
 
 fn main() -> anyhow::Result<()> {
-    let options = common::simple_cli::Opts::get();
+    let options = Opts::parse();
 
-    let file_content = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.common.source_for::<Day03>()?)?;
 
-    let field = file_content.parse::<Field>()?;
+    if let Some(runs) = options.common.bench {
+        let bench = common::bench::bench::<Day03>(&content, runs)?;
+        options.common.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    let result = [(1usize, 1usize), (3, 1), (5, 1), (7, 1), (1, 2)]
-        .iter()
-        .map(|s| test_slope(&field, *s))
-        .product::<usize>();
+    if options.render {
+        let field = Day03::parse(&content)?;
+        let mut renderer = Renderer::new(&field).with_color(io::stdout().is_terminal());
+        for slope in slopes(&options, &field)? {
+            renderer = renderer.with_slope(slope);
+        }
+        print!("{}", renderer);
+        return Ok(());
+    }
 
-    println!("Product: {}", result);
+    let report = if options.slopes.is_empty() && options.best.is_none() && !options.path {
+        common::solution::solve::<Day03>(&content)?
+            .with_label(Part::One, "Trees hit with (3, 1) slope")
+            .with_label(Part::Two, "Product of trees hit on all slopes")
+    } else {
+        explore_report(&options, &Day03::parse(&content)?)?
+    };
 
-    Ok(())
-}
+    options.common.format.print(&[report])?;
 
-fn test_slope(field: &Field, slope: (usize, usize)) -> usize {
-    let trees_hit = traverse_slope(field, slope.0, slope.1);
-    println!(
-        "With ({}, {}) slope: hit {} tree(s)",
-        slope.0, slope.1, trees_hit
-    );
-    trees_hit
+    Ok(())
 }
 
-fn traverse_slope(field: &Field, delta_x: usize, delta_y: usize) -> usize {
-    // start position
-    let mut pos = Point(0usize, 0usize);
-    // distance to bottom (last y position)
-    let target = field.len() - 1;
-
-    // tree counter
-    let mut counter = 0usize;
-
-    while pos.1 < target {
-        pos += (delta_x, delta_y);
-
-        if field.is_tree_at(pos.0, pos.1) {
-            counter += 1;
-        }
+/// The best slope if searched, else the given ones or those of part 2
+fn slopes(options: &Opts, field: &Field) -> anyhow::Result<Vec<Slope>> {
+    match (options.best, options.slopes.as_slice()) {
+        (Some(goal), _) => day03::best_slope(field, options.bound, goal)
+            .map(|best| vec![best.slope])
+            .context("no slopes within a bound of 0"),
+        (None, []) => Ok(day03::SLOPES.to_vec()),
+        (None, slopes) => Ok(slopes.to_vec()),
     }
-
-    counter
 }
 
-#[derive(Debug)]
-pub struct Point(usize, usize);
+/// A part per slope with the product of all, or the best slope if searched
+fn explore_report(options: &Opts, field: &Field) -> anyhow::Result<Report> {
+    let mut report = Report::new(Day03::DAY);
+    let with_path = |traversal: Traversal| match options.path {
+        true => traversal.with_path(field),
+        false => traversal,
+    };
+
+    if let Some(goal) = options.best {
+        let part = Part::Extra("best".to_owned());
+        let label = match goal {
+            Goal::Fewest => "Slope hitting the fewest trees",
+            Goal::Most => "Slope hitting the most trees",
+        };
+        let result = PartResult::try_timed(part.clone(), || {
+            day03::best_slope(field, options.bound, goal)
+                .map(with_path)
+                .context("no slopes within a bound of 0")
+        })?;
+        return Ok(report.with_part(result).with_label(part, label));
+    }
 
-impl std::ops::AddAssign<(usize, usize)> for Point {
-    fn add_assign(&mut self, (x, y): (usize, usize)) {
-        self.0 += x;
-        self.1 += y;
+    let slopes = match options.slopes.as_slice() {
+        [] => &day03::SLOPES[..],
+        slopes => slopes,
+    };
+    for &slope in slopes {
+        let part = Part::Extra(slope.to_string());
+        let result = PartResult::timed(part.clone(), || {
+            with_path(Traversal::new(slope, day03::traverse_slope(field, slope)))
+        });
+        report = report
+            .with_part(result)
+            .with_label(part, format!("Trees hit with {} slope", slope));
     }
+
+    let part = Part::Extra("product".to_owned());
+    Ok(report
+        .with_part(PartResult::timed(part.clone(), || {
+            day03::slope_product(field, slopes)
+        }))
+        .with_label(part, "Product of trees hit on all slopes"))
 }
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use common::Point;
+
+use crate::field::{Field, FieldState};
+use crate::slope::Slope;
+
+/// ANSI colors of the paths, repeating if there are more
+const COLORS: [u8; 6] = [31, 32, 33, 34, 35, 36];
+
+/// Repeated map with the paths overlaid, 'O' for open squares and 'X' for trees hit
+///
+/// Repeats the map as far as the paths go, to the left as well. Where paths cross,
+/// the one added first is shown
+#[derive(Debug, Clone)]
+pub struct Renderer<'a> {
+    field: &'a Field,
+    paths: Vec<(Slope, Vec<Point<isize>>)>,
+    color: bool,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(field: &'a Field) -> Self {
+        Self {
+            field,
+            paths: Vec::new(),
+            color: false,
+        }
+    }
+
+    pub fn with_slope(mut self, slope: Slope) -> Self {
+        self.paths.push((slope, self.field.path(slope)));
+        self
+    }
+
+    /// Color each path differently with ANSI escape codes, e.g. when writing to a terminal
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Index of the first path reaching the position
+    fn path_at(&self, x: isize, y: isize) -> Option<usize> {
+        self.paths
+            .iter()
+            .position(|(_, path)| path.contains(&Point { x, y }))
+    }
+
+    /// Columns to show, at least one repetition of the map
+    fn columns(&self) -> (isize, isize) {
+        let xs = self
+            .paths
+            .iter()
+            .flat_map(|(_, path)| path.iter().map(|p| p.x));
+        let min = xs.clone().min().unwrap_or(0).min(0);
+        let max = xs.max().unwrap_or(0).max(self.field.width() as isize - 1);
+        (min, max)
+    }
+}
+
+impl<'a> fmt::Display for Renderer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.columns();
+
+        if self.color {
+            for (idx, (slope, _)) in self.paths.iter().enumerate() {
+                let color = COLORS[idx % COLORS.len()];
+                writeln!(f, "\x1b[{}m{}\x1b[0m", color, slope)?;
+            }
+        }
+
+        for y in 0..self.field.len() {
+            for x in min..=max {
+                let state = self.field.get_field(x, y);
+                let path = match self.path_at(x, y as isize) {
+                    Some(path) => path,
+                    None => {
+                        write!(f, "{}", state)?;
+                        continue;
+                    }
+                };
+
+                let mark = match state {
+                    FieldState::Empty => 'O',
+                    FieldState::Tree => 'X',
+                };
+                if self.color {
+                    let color = COLORS[path % COLORS.len()];
+                    write!(f, "\x1b[{}m{}\x1b[0m", color, mark)?;
+                } else {
+                    write!(f, "{}", mark)?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let field = crate::parse(common::example_input!("1")).unwrap();
+        let rendered = Renderer::new(&field)
+            .with_slope(Slope::new(3, 1))
+            .to_string();
+
+        let expected = "\
+..##.........##.........##.....
+#..O#...#..#...#...#..#...#...#
+.#....X..#..#....#..#..#....#..
+..#.#...#O#..#.#...#.#..#.#...#
+.#...##..#..X...##..#..#...##..
+..#.##.......#.X#.......#.##...
+.#.#.#....#.#.#.#.O..#.#.#.#...
+.#........#.#........X.#.......
+#.##...#...#.##...#...#.X#...#.
+#...##....##...##....##...#X...
+.#..#...#.#.#..#...#.#.#..#...X
+";
+        assert_eq!(rendered, expected);
+    }
+}
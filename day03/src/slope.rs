@@ -0,0 +1,114 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub use error::SlopeParseError;
+
+// Slope
+//##################
+
+/// Step of the toboggan, a negative right goes left
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub const fn new(right: isize, down: usize) -> Self {
+        Self { right, down }
+    }
+
+    /// Every slope with right in -bound..=bound and down in 1..=bound, ordered by down
+    pub fn all_within(bound: usize) -> impl Iterator<Item = Slope> {
+        let bound_right = bound as isize;
+        (1..=bound).flat_map(move |down| {
+            (-bound_right..=bound_right).map(move |right| Slope::new(right, down))
+        })
+    }
+}
+
+/// Parse slope from string
+impl FromStr for Slope {
+    type Err = SlopeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Slope format:
+        // RIGHT,DOWN
+
+        // RIGHT is Integer, negative to go left
+        // DOWN is positive Integer
+        let (right, down) = s.split_once(',').ok_or(SlopeParseError::Malformed)?;
+
+        let right = right
+            .trim()
+            .parse::<isize>()
+            .map_err(SlopeParseError::RightParseError)?;
+        let down = down
+            .trim()
+            .parse::<usize>()
+            .map_err(SlopeParseError::DownParseError)?;
+
+        // the toboggan would never reach the bottom
+        if down == 0 {
+            return Err(SlopeParseError::NotDownwards);
+        }
+
+        Ok(Slope::new(right, down))
+    }
+}
+
+/// Format: (RIGHT, DOWN)
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.right, self.down)
+    }
+}
+
+//##################
+
+// Goal
+//##################
+
+/// What the best slope hits
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Goal {
+    Fewest,
+    Most,
+}
+
+impl FromStr for Goal {
+    type Err = SlopeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fewest" => Ok(Goal::Fewest),
+            "most" => Ok(Goal::Most),
+            goal => Err(SlopeParseError::UnknownGoal(goal.to_owned())),
+        }
+    }
+}
+
+//##################
+
+pub mod error {
+    use std::num;
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    pub enum SlopeParseError {
+        #[error("input string malformed, expected RIGHT,DOWN")]
+        Malformed,
+
+        #[error("failed to parse right: {0}")]
+        RightParseError(num::ParseIntError),
+
+        #[error("failed to parse down: {0}")]
+        DownParseError(num::ParseIntError),
+
+        #[error("slope has to go down at least 1")]
+        NotDownwards,
+
+        #[error("unknown goal: {0}, expected fewest or most")]
+        UnknownGoal(String),
+    }
+}
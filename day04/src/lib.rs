@@ -0,0 +1,119 @@
+pub mod passport;
+pub mod reader;
+pub mod schema;
+pub mod validation;
+
+use std::io::BufRead;
+
+use passport::Batch;
+use reader::{BatchReader, ReadError};
+use schema::Schema;
+use validation::PassportReport;
+
+/// Batch of passports, borrowed from the input
+pub type Input<'a> = Batch<'a>;
+
+/// Parsing never fails, unknown fields are kept as such
+pub type Error = std::convert::Infallible;
+
+/// Parse the batch file, passports are separated by blank lines
+pub fn parse(input: &str) -> Result<Input<'_>, Error> {
+    Ok(Batch::from(input))
+}
+
+/// Count passports containing all required fields
+pub fn part1(input: &Input<'_>) -> usize {
+    count_complete(input, Schema::puzzle())
+}
+
+/// Count passports containing all required fields with valid values
+pub fn part2(input: &Input<'_>) -> usize {
+    count_valid(input, Schema::puzzle())
+}
+
+/// Count passports containing all fields the schema requires
+pub fn count_complete(input: &Input<'_>, schema: &Schema) -> usize {
+    input
+        .passports()
+        .iter()
+        .filter(|p| schema.is_complete(p))
+        .count()
+}
+
+/// Count passports following all rules of the schema
+pub fn count_valid(input: &Input<'_>, schema: &Schema) -> usize {
+    input
+        .passports()
+        .iter()
+        .filter(|p| schema.is_valid(p))
+        .count()
+}
+
+/// Everything wrong with each passport of the batch
+pub fn validation_report(input: &Input<'_>, schema: &Schema) -> Vec<PassportReport> {
+    input
+        .passports()
+        .iter()
+        .enumerate()
+        .map(|(idx, passport)| PassportReport::check(idx + 1, passport, schema))
+        .collect()
+}
+
+/// Count complete and valid passports while reading them one by one
+pub fn count_streamed(reader: impl BufRead, schema: &Schema) -> Result<(usize, usize), ReadError> {
+    let mut complete = 0;
+    let mut valid = 0;
+
+    for record in BatchReader::new(reader) {
+        let record = record?;
+        let passport = record.passport();
+        complete += schema.is_complete(&passport) as usize;
+        valid += schema.is_valid(&passport) as usize;
+    }
+
+    Ok((complete, valid))
+}
+
+/// Everything wrong with each passport, reading them one by one
+pub fn streamed_report<'a>(
+    reader: impl BufRead + 'a,
+    schema: &'a Schema,
+) -> impl Iterator<Item = Result<PassportReport, ReadError>> + 'a {
+    BatchReader::new(reader)
+        .enumerate()
+        .map(move |(idx, record)| Ok(PassportReport::check(idx + 1, &record?.passport(), schema)))
+}
+
+pub struct Day04;
+
+impl common::Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Input<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day04,
+        example_1: common::example_input!("1") => { part1: 2 },
+        invalid: common::example_input!("2") => { part2: 0 },
+        valid: common::example_input!("3") => { part2: 4 },
+    }
+}
@@ -1,133 +1,131 @@
-mod passport;
-
-use std::fs;
-use std::result::Result::Ok;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use anyhow::Context;
+
+use clap::Clap;
+
+use common::input::Source;
+use common::report::{Part, PartResult, Report};
+use common::Solution;
+use day04::schema::Schema;
+use day04::validation::{ReportFormat, ReportWriter};
+use day04::Day04;
+
+#[derive(Clap)]
+#[clap(
+    version = "1.0",
+    author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
+)]
+struct Opts {
+    #[clap(flatten)]
+    common: common::simple_cli::Opts,
+
+    /// Check passports against the rules in this .toml or .json file instead of the puzzle's
+    #[clap(long, value_name = "FILE", conflicts_with = "bench")]
+    schema: Option<PathBuf>,
+
+    /// Print why each passport is valid or not as table or json instead of counting
+    #[clap(long, value_name = "FORMAT", possible_values = &["table", "json"])]
+    report: Option<ReportFormat>,
+
+    /// Read the batch one passport at a time instead of loading it whole, for huge files
+    ///
+    /// Reports are written as the passports are checked, tables with fixed column widths
+    #[clap(long, conflicts_with = "bench")]
+    stream: bool,
+}
 
 fn main() -> anyhow::Result<()> {
-    let options = common::simple_cli::Opts::get();
+    let options = Opts::parse();
+    let source = options.common.source_for::<Day04>()?;
 
-    let content = fs::read_to_string(options.input)?;
+    if options.stream {
+        return stream(&options, &source);
+    }
 
-    let batch = passport::Batch::from(content.as_str());
+    let content = common::input::load(&source)?;
 
-    let required_fields = get_requirements();
+    if let Some(runs) = options.common.bench {
+        let bench = common::bench::bench::<Day04>(&content, runs)?;
+        options.common.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // Part 1
-    let part1_count = batch
-        .get_passports_containing_fields(&required_fields)
-        .len();
+    let schema = schema(&options)?;
 
-    println!("Part 1: {} valid passports", part1_count);
+    if let Some(format) = options.report {
+        let reports = day04::validation_report(&Day04::parse(&content)?, &schema);
+        format.write(&reports, &mut io::stdout().lock())?;
+        return Ok(());
+    }
 
-    // Part 2
-    let part2_count = batch
-        .get_passports_containing_fields(&required_fields)
-        .iter()
-        .filter(|p| is_valid_passport(p, &required_fields))
-        .count();
+    let report = match &options.schema {
+        Some(_) => schema_report(&schema, &content)?,
+        None => common::solution::solve::<Day04>(&content)?,
+    }
+    .with_label(Part::One, "Part 1 valid passports")
+    .with_label(Part::Two, "Part 2 valid passports");
 
-    println!("Part 2: {} valid passports", part2_count);
+    options.common.format.print(&[report])?;
 
     Ok(())
 }
 
-fn get_requirements() -> [passport::PassportFieldParse<'static>; 7] {
-    use passport::PassportFieldParse::*;
-
-    [
-        BirthYear,
-        IssueYear,
-        ExpirationYear,
-        Height,
-        HairColor,
-        EyeColor,
-        PassportID,
-    ]
+/// Both parts with the rules of schema
+fn schema_report(schema: &Schema, content: &str) -> anyhow::Result<Report> {
+    let input = Day04::parse(content)?;
+
+    Ok(Report::new(Day04::DAY)
+        .with_part(PartResult::timed(Part::One, || {
+            day04::count_complete(&input, schema)
+        }))
+        .with_part(PartResult::timed(Part::Two, || {
+            day04::count_valid(&input, schema)
+        })))
 }
 
-fn is_valid_passport(
-    passport: &passport::PassportParse,
-    fields: &[passport::PassportFieldParse],
-) -> bool {
-    passport
-        .fields
-        .iter()
-        .filter(|(t, _)| fields.contains(t)) // Only check required fields
-        .all(|field| is_valid_field(field)) // all remaining fields have to be valid
+/// Schema file from the options or the puzzle's rules
+fn schema(options: &Opts) -> anyhow::Result<Schema> {
+    Ok(match &options.schema {
+        Some(path) => Schema::load(path)?,
+        None => Schema::puzzle().clone(),
+    })
 }
 
-fn is_valid_field(field: &(passport::PassportFieldParse, &str)) -> bool {
-    use passport::PassportFieldParse::*;
-
-    // use specified method for valid checking
-    match field {
-        (BirthYear, value) => validate_year(value, 1920, 2002),
-        (IssueYear, value) => validate_year(value, 2010, 2020),
-        (ExpirationYear, value) => validate_year(value, 2020, 2030),
-        (Height, value) => validate_height(value),
-        (HairColor, value) => validate_hair_color(value),
-        (EyeColor, value) => validate_eye_color(value),
-        (PassportID, value) => validate_passport_id(value),
-        _ => unreachable!("CountryID and Unknown should have been filtered out"),
-    }
-}
-
-// parse as int and check if between given values
-fn validate_year(value: &str, bigger_or_equal: usize, smaller_or_equal: usize) -> bool {
-    let year = if let Ok(year) = value.parse::<usize>() {
-        year
-    } else {
-        return false;
+/// Counts or report without holding the whole batch in memory
+fn stream(options: &Opts, source: &Source) -> anyhow::Result<()> {
+    let schema = schema(options)?;
+    let reader: Box<dyn BufRead + '_> = match source {
+        Source::Stdin => Box::new(io::stdin().lock()),
+        Source::File(path) => Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?,
+        )),
+        Source::Inline(content) => Box::new(content.as_bytes()),
     };
 
-    bigger_or_equal <= year && year <= smaller_or_equal
-}
-
-// check for suffix cm or in, strip it, parse rest as int and check
-fn validate_height(value: &str) -> bool {
-    if let Some(stripped) = value.strip_suffix("cm") {
-        if let Ok(height_cm) = stripped.parse::<usize>() {
-            150 <= height_cm && height_cm <= 193
-        } else {
-            false
-        }
-    } else if let Some(stripped) = value.strip_suffix("in") {
-        if let Ok(height_in) = stripped.parse::<usize>() {
-            59 <= height_in && height_in <= 76
-        } else {
-            false
+    if let Some(format) = options.report {
+        let mut writer = ReportWriter::new(format, io::stdout().lock());
+        for report in day04::streamed_report(reader, &schema) {
+            writer.write(&report?)?;
         }
-    } else {
-        false
-    }
-}
-
-// check for prefix #, strip it, filter remaining chars if hex component and count fitting, should be 6
-fn validate_hair_color(value: &str) -> bool {
-    if let Some(without_pound) = value.strip_prefix('#') {
-        without_pound
-            .chars()
-            .filter(|c| char_is_hex_component(c))
-            .count()
-            == 6
-    } else {
-        false
+        writer.finish()?;
+        return Ok(());
     }
-}
-fn char_is_hex_component(char: &char) -> bool {
-    matches!(char, '0'..='9' | 'a'..='f')
-}
 
-// check if value is one of specified
-fn validate_eye_color(value: &str) -> bool {
-    matches!(value, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth")
-}
+    // both parts are counted in the same pass and share its time
+    let start = Instant::now();
+    let (complete, valid) = day04::count_streamed(reader, &schema)?;
+    let elapsed = start.elapsed();
 
-// filter chars of value for numerical chars and count remaining, should be 9
-fn validate_passport_id(value: &str) -> bool {
-    value.chars().filter(|c| char_is_number(c)).count() == 9
-}
+    let report = Report::new(Day04::DAY)
+        .with_part(PartResult::new(Part::One, &complete, elapsed))
+        .with_part(PartResult::new(Part::Two, &valid, elapsed))
+        .with_label(Part::One, "Part 1 valid passports")
+        .with_label(Part::Two, "Part 2 valid passports");
+    options.common.format.print(&[report])?;
 
-fn char_is_number(char: &char) -> bool {
-    matches!(char, '0'..='9')
+    Ok(())
 }
@@ -1,5 +1,15 @@
 use std::collections::HashSet;
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+use crate::reader;
+use crate::schema::{InvalidValue, Schema};
+
+pub use error::FieldError;
+pub use error::PassportError;
+pub use error::PassportFieldError;
 
 // Batch
 //##################
@@ -9,10 +19,14 @@ pub struct Batch<'a> {
 }
 
 impl<'a> Batch<'a> {
+    pub fn passports(&self) -> &[PassportParse<'a>] {
+        &self.passports
+    }
+
     pub fn get_passports_containing_fields(
         &'a self,
         requirements: &[PassportFieldParse],
-    ) -> Vec<&PassportParse<'a>> {
+    ) -> Vec<&'a PassportParse<'a>> {
         self.passports
             .iter()
             .filter(|p| p.contain_fields(requirements))
@@ -22,7 +36,9 @@ impl<'a> Batch<'a> {
 
 impl<'a> From<&'a str> for Batch<'a> {
     fn from(s: &'a str) -> Self {
-        let passports = s.split("\n\n").map(PassportParse::from).collect::<Vec<_>>();
+        let passports = reader::records(s)
+            .map(|(line, record)| PassportParse::from(record).at_line(line))
+            .collect::<Vec<_>>();
         Self { passports }
     }
 }
@@ -33,10 +49,17 @@ impl<'a> From<&'a str> for Batch<'a> {
 //##################
 
 pub struct PassportParse<'a> {
+    /// Line of the batch the passport starts at, starting at 1
+    pub line: usize,
     pub fields: Vec<(PassportFieldParse<'a>, &'a str)>,
 }
 
 impl<'a> PassportParse<'a> {
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
     pub fn contain_fields(&'a self, requirements: &[PassportFieldParse]) -> bool {
         // lazy approach: collect to hashset and intersect.
         // if intersection contains as many fields as requirements, then all required fields are there
@@ -47,6 +70,14 @@ impl<'a> PassportParse<'a> {
 
         requirement_set.intersection(&passport_set).count() == requirements.len()
     }
+
+    /// Value of the first field with the ID
+    pub fn get(&self, id: &str) -> Option<&'a str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field.id() == id)
+            .map(|(_, value)| *value)
+    }
 }
 
 impl<'a> From<&'a str> for PassportParse<'a> {
@@ -70,7 +101,7 @@ impl<'a> From<&'a str> for PassportParse<'a> {
             })
             .collect::<Vec<_>>();
 
-        Self { fields }
+        Self { line: 1, fields }
     }
 }
 
@@ -92,6 +123,25 @@ pub enum PassportFieldParse<'a> {
     Unknown(&'a str),
 }
 
+impl<'a> PassportFieldParse<'a> {
+    /// The three letter ID used in the batch file
+    pub fn id(&self) -> &'a str {
+        use PassportFieldParse::*;
+
+        match self {
+            BirthYear => "byr",
+            IssueYear => "iyr",
+            ExpirationYear => "eyr",
+            Height => "hgt",
+            HairColor => "hcl",
+            EyeColor => "ecl",
+            PassportID => "pid",
+            CountryID => "cid",
+            Unknown(unknown) => unknown,
+        }
+    }
+}
+
 impl<'a> From<&'a str> for PassportFieldParse<'a> {
     fn from(s: &'a str) -> Self {
         use PassportFieldParse::*;
@@ -111,3 +161,428 @@ impl<'a> From<&'a str> for PassportFieldParse<'a> {
 }
 
 //##################
+
+// Passport
+//##################
+
+/// Passport with all fields validated and typed
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Passport {
+    pub birth_year: BirthYear,
+    pub issue_year: IssueYear,
+    pub expiration_year: ExpirationYear,
+    pub height: Height,
+    pub hair_color: HairColor,
+    pub eye_color: EyeColor,
+    pub passport_id: PassportID,
+    pub country_id: Option<CountryID>,
+}
+
+impl Passport {
+    /// Check the fields with the rules of schema before typing them
+    ///
+    /// Every failing field is reported, in the order of the struct
+    pub fn parse_with(passport: &PassportParse, schema: &Schema) -> Result<Self, PassportError> {
+        use PassportFieldError as E;
+
+        let mut errors = Vec::new();
+        let birth_year = collect_field(passport, schema, "byr", E::BirthYear, &mut errors);
+        let issue_year = collect_field(passport, schema, "iyr", E::IssueYear, &mut errors);
+        let expiration_year =
+            collect_field(passport, schema, "eyr", E::ExpirationYear, &mut errors);
+        let height = collect_field(passport, schema, "hgt", E::Height, &mut errors);
+        let hair_color = collect_field(passport, schema, "hcl", E::HairColor, &mut errors);
+        let eye_color = collect_field(passport, schema, "ecl", E::EyeColor, &mut errors);
+        let passport_id = collect_field(passport, schema, "pid", E::PassportID, &mut errors);
+        let country_id = match typed_field(passport, schema, "cid") {
+            Ok(country_id) => Some(Some(country_id)),
+            Err(FieldError::Missing) => Some(None),
+            Err(err) => {
+                errors.push(E::CountryID(err));
+                None
+            }
+        };
+
+        match (
+            birth_year,
+            issue_year,
+            expiration_year,
+            height,
+            hair_color,
+            eye_color,
+            passport_id,
+            country_id,
+        ) {
+            (
+                Some(birth_year),
+                Some(issue_year),
+                Some(expiration_year),
+                Some(height),
+                Some(hair_color),
+                Some(eye_color),
+                Some(passport_id),
+                Some(country_id),
+            ) => Ok(Self {
+                birth_year,
+                issue_year,
+                expiration_year,
+                height,
+                hair_color,
+                eye_color,
+                passport_id,
+                country_id,
+            }),
+            _ => Err(PassportError(errors)),
+        }
+    }
+}
+
+/// Validated by the rules of part 2
+impl<'a> TryFrom<&PassportParse<'a>> for Passport {
+    type Error = PassportError;
+
+    fn try_from(passport: &PassportParse<'a>) -> Result<Self, Self::Error> {
+        Passport::parse_with(passport, Schema::puzzle())
+    }
+}
+
+/// Value of the field checked by its rule and parsed
+fn typed_field<T>(passport: &PassportParse, schema: &Schema, id: &str) -> Result<T, FieldError>
+where
+    T: FromStr<Err = InvalidValue>,
+{
+    let value = passport.get(id).ok_or(FieldError::Missing)?;
+    if let Some(rule) = schema.rule(id) {
+        rule.kind.check(value)?;
+    }
+    Ok(value.parse()?)
+}
+
+/// Typed field, None after pushing its error
+fn collect_field<T>(
+    passport: &PassportParse,
+    schema: &Schema,
+    id: &str,
+    error: fn(FieldError) -> PassportFieldError,
+    errors: &mut Vec<PassportFieldError>,
+) -> Option<T>
+where
+    T: FromStr<Err = InvalidValue>,
+{
+    typed_field(passport, schema, id)
+        .map_err(|err| errors.push(error(err)))
+        .ok()
+}
+
+//##################
+
+// Passport fields
+//##################
+
+/// Numbers beyond u16 are too large, even if a schema allows them
+fn parse_u16(s: &str) -> Result<u16, InvalidValue> {
+    s.parse().map_err(|err: ParseIntError| match err.kind() {
+        IntErrorKind::PosOverflow => InvalidValue::TooLarge {
+            value: s.to_owned(),
+            max: u16::MAX.into(),
+        },
+        _ => InvalidValue::NotANumber(s.to_owned()),
+    })
+}
+
+macro_rules! year_field {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            pub struct $name(pub u16);
+
+            impl FromStr for $name {
+                type Err = InvalidValue;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_u16(s).map($name)
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+        )*
+    };
+}
+
+year_field!(BirthYear, IssueYear, ExpirationYear);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Height {
+    Cm(u16),
+    In(u16),
+}
+
+impl FromStr for Height {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit): (_, fn(u16) -> Height) = if let Some(cm) = s.strip_suffix("cm") {
+            (cm, Height::Cm)
+        } else if let Some(inches) = s.strip_suffix("in") {
+            (inches, Height::In)
+        } else {
+            return Err(InvalidValue::UnknownUnit("cm, in".to_owned()));
+        };
+
+        parse_u16(number).map(unit)
+    }
+}
+
+impl fmt::Display for Height {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Height::Cm(cm) => write!(f, "{}cm", cm),
+            Height::In(inches) => write!(f, "{}in", inches),
+        }
+    }
+}
+
+/// Red, green and blue
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HairColor(pub [u8; 3]);
+
+impl FromStr for HairColor {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').ok_or(InvalidValue::NotAHexColor)?;
+        if hex.len() != 6 {
+            return Err(InvalidValue::NotAHexColor);
+        }
+
+        let mut rgb = [0; 3];
+        for (idx, component) in rgb.iter_mut().enumerate() {
+            let digits = hex
+                .get(idx * 2..idx * 2 + 2)
+                .ok_or(InvalidValue::NotAHexColor)?;
+            *component = u8::from_str_radix(digits, 16).map_err(|_| InvalidValue::NotAHexColor)?;
+        }
+        Ok(HairColor(rgb))
+    }
+}
+
+/// Format: #rrggbb
+impl fmt::Display for HairColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b] = self.0;
+        write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EyeColor {
+    Amber,
+    Blue,
+    Brown,
+    Gray,
+    Green,
+    Hazel,
+    Other,
+}
+
+impl FromStr for EyeColor {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use EyeColor::*;
+
+        match s {
+            "amb" => Ok(Amber),
+            "blu" => Ok(Blue),
+            "brn" => Ok(Brown),
+            "gry" => Ok(Gray),
+            "grn" => Ok(Green),
+            "hzl" => Ok(Hazel),
+            "oth" => Ok(Other),
+            _ => Err(InvalidValue::NotOneOf(
+                "amb, blu, brn, gry, grn, hzl, oth".to_owned(),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for EyeColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use EyeColor::*;
+
+        f.write_str(match self {
+            Amber => "amb",
+            Blue => "blu",
+            Brown => "brn",
+            Gray => "gry",
+            Green => "grn",
+            Hazel => "hzl",
+            Other => "oth",
+        })
+    }
+}
+
+/// Digits only, leading zeros are kept
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PassportID(String);
+
+impl PassportID {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PassportID {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+            return Err(InvalidValue::NotANumber(s.to_owned()));
+        }
+        Ok(PassportID(s.to_owned()))
+    }
+}
+
+impl fmt::Display for PassportID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Not checked, may be anything
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CountryID(pub String);
+
+impl FromStr for CountryID {
+    type Err = InvalidValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(CountryID(s.to_owned()))
+    }
+}
+
+//##################
+
+pub mod error {
+    use thiserror::Error;
+
+    use crate::schema::InvalidValue;
+
+    /// Every field that kept the passport from being typed, in the order of the struct
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    pub struct PassportError(pub Vec<PassportFieldError>);
+
+    impl PassportError {
+        pub fn fields(&self) -> &[PassportFieldError] {
+            &self.0
+        }
+    }
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum PassportFieldError {
+        #[error("birth year (byr) {0}")]
+        BirthYear(FieldError),
+
+        #[error("issue year (iyr) {0}")]
+        IssueYear(FieldError),
+
+        #[error("expiration year (eyr) {0}")]
+        ExpirationYear(FieldError),
+
+        #[error("height (hgt) {0}")]
+        Height(FieldError),
+
+        #[error("hair color (hcl) {0}")]
+        HairColor(FieldError),
+
+        #[error("eye color (ecl) {0}")]
+        EyeColor(FieldError),
+
+        #[error("passport id (pid) {0}")]
+        PassportID(FieldError),
+
+        #[error("country id (cid) {0}")]
+        CountryID(FieldError),
+    }
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum FieldError {
+        #[error("missing")]
+        Missing,
+
+        #[error("invalid: {0}")]
+        Invalid(#[from] InvalidValue),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldRule, FieldType};
+
+    #[test]
+    fn typed() {
+        let parse = PassportParse::from(
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\nhcl:#623a2f",
+        );
+        let passport = Passport::try_from(&parse).unwrap();
+
+        assert_eq!(passport.birth_year, BirthYear(1980));
+        assert_eq!(passport.height, Height::In(74));
+        assert_eq!(passport.hair_color, HairColor([0x62, 0x3a, 0x2f]));
+        assert_eq!(passport.hair_color.to_string(), "#623a2f");
+        assert_eq!(passport.eye_color, EyeColor::Green);
+        assert_eq!(passport.passport_id.as_str(), "087499704");
+        assert_eq!(passport.country_id, None);
+
+        // schemas may allow values the typed fields can not hold
+        let schema = Schema::puzzle()
+            .clone()
+            .with_field("byr", FieldRule::new(FieldType::Digits { len: 5 }, true));
+        let parse = PassportParse::from(
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:70000 hcl:#623a2f",
+        );
+        assert_eq!(
+            Passport::parse_with(&parse, &schema).unwrap_err().fields(),
+            [PassportFieldError::BirthYear(FieldError::Invalid(
+                InvalidValue::TooLarge {
+                    value: "70000".to_owned(),
+                    max: 65535
+                }
+            ))]
+        );
+    }
+
+    #[test]
+    fn failing_fields() {
+        let parse = PassportParse::from(
+            "eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926",
+        );
+        assert_eq!(
+            Passport::try_from(&parse).unwrap_err().fields(),
+            [
+                PassportFieldError::ExpirationYear(FieldError::Invalid(InvalidValue::OutOfRange {
+                    value: 1972,
+                    min: 2020,
+                    max: 2030
+                })),
+                PassportFieldError::Height(FieldError::Invalid(InvalidValue::UnknownUnit(
+                    "cm, in".to_owned()
+                ))),
+                PassportFieldError::PassportID(FieldError::Invalid(InvalidValue::NotDigits(9))),
+            ]
+        );
+
+        let parse = PassportParse::from("byr:1926 iyr:2018");
+        assert_eq!(
+            Passport::try_from(&parse).unwrap_err().to_string(),
+            "expiration year (eyr) missing, height (hgt) missing, hair color (hcl) missing, \
+             eye color (ecl) missing, passport id (pid) missing"
+        );
+    }
+}
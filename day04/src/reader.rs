@@ -0,0 +1,188 @@
+use std::io::BufRead;
+
+use crate::passport::PassportParse;
+
+pub use error::ReadError;
+
+/// Lines with nothing but whitespace separate records
+pub fn is_separator(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Records of a batch held in memory, with the line number each starts at
+pub fn records(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut lines = input.split('\n').enumerate().peekable();
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        // skip separators in front of the record
+        while let Some((_, line)) = lines.next_if(|(_, line)| is_separator(line)) {
+            offset += line.len() + 1;
+        }
+
+        let (idx, _) = *lines.peek()?;
+        let start = offset;
+        while let Some((_, line)) = lines.next_if(|(_, line)| !is_separator(line)) {
+            offset += line.len() + 1;
+        }
+
+        let end = offset.min(input.len());
+        Some((idx + 1, &input[start..end]))
+    })
+}
+
+// Record
+//##################
+
+/// Lines of one passport, owned by the reader's caller
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Record {
+    /// Line the record starts at, starting at 1
+    pub line: usize,
+    pub content: String,
+}
+
+impl Record {
+    pub fn passport(&self) -> PassportParse<'_> {
+        PassportParse::from(self.content.as_str()).at_line(self.line)
+    }
+}
+
+//##################
+
+// BatchReader
+//##################
+
+/// Reads a batch one record at a time, so the whole file never has to be in memory
+///
+/// Accepts LF and CRLF line endings
+pub struct BatchReader<R> {
+    reader: R,
+    /// Number of the last line read
+    line: usize,
+    buffer: String,
+}
+
+impl<R: BufRead> BatchReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: 0,
+            buffer: String::new(),
+        }
+    }
+
+    /// Read the next line into the buffer, false at the end of the input
+    fn next_line(&mut self) -> Result<bool, ReadError> {
+        self.buffer.clear();
+        self.line += 1;
+
+        let read = self
+            .reader
+            .read_line(&mut self.buffer)
+            .map_err(|source| ReadError {
+                line: self.line,
+                source,
+            })?;
+
+        Ok(read > 0)
+    }
+}
+
+impl<R: BufRead> Iterator for BatchReader<R> {
+    type Item = Result<Record, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record: Option<Record> = None;
+
+        loop {
+            match self.next_line() {
+                Ok(true) => {}
+                Ok(false) => return record.map(Ok),
+                Err(err) => return Some(Err(err)),
+            }
+
+            let line = self.buffer.trim_end_matches(&['\n', '\r'][..]);
+
+            if is_separator(line) {
+                if record.is_some() {
+                    return record.map(Ok);
+                }
+                continue;
+            }
+
+            match &mut record {
+                Some(record) => {
+                    record.content.push('\n');
+                    record.content.push_str(line);
+                }
+                None => {
+                    record = Some(Record {
+                        line: self.line,
+                        content: line.to_owned(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+//##################
+
+pub mod error {
+    use std::io;
+
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    #[error("can not read line {line} of the batch")]
+    pub struct ReadError {
+        pub line: usize,
+        #[source]
+        pub source: io::Error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BATCH: &str =
+        "\r\necl:gry pid:860033327\r\nbyr:1937\r\n\r\n  \t\r\n\r\niyr:2013\n \nhcl:#cfa07d\n";
+
+    #[test]
+    fn separators() {
+        let streamed = BatchReader::new(BATCH.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let expected = [
+            (2, "ecl:gry pid:860033327\nbyr:1937"),
+            (7, "iyr:2013"),
+            (9, "hcl:#cfa07d"),
+        ];
+        assert_eq!(streamed.len(), expected.len());
+        for (record, (line, content)) in streamed.iter().zip(&expected) {
+            assert_eq!(record.line, *line);
+            assert_eq!(record.content, *content);
+        }
+
+        let in_memory = records(BATCH)
+            .map(|(line, record)| (line, record.split_whitespace().collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        let expected = expected
+            .iter()
+            .map(|(line, content)| (*line, content.split_whitespace().collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        assert_eq!(in_memory, expected);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let err = BatchReader::new(&b"byr:1937\n\xff\n"[..])
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::passport::PassportParse;
+
+pub use error::InvalidValue;
+pub use error::SchemaError;
+
+/// Rules of part 2, also an example of the format
+const PUZZLE_SCHEMA: &str = include_str!("../schema.toml");
+
+// Schema
+//##################
+
+/// Rules for the fields of a passport keyed by field ID
+///
+/// Stored as toml or json:
+/// ```toml
+/// [fields.byr]
+/// type = "year"
+/// min = 1920
+/// max = 2002
+/// required = true
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Schema {
+    #[serde(default)]
+    fields: BTreeMap<String, FieldRule>,
+}
+
+impl Schema {
+    /// The rules of part 2, parsed once
+    pub fn puzzle() -> &'static Self {
+        static PUZZLE: OnceLock<Schema> = OnceLock::new();
+        PUZZLE.get_or_init(|| {
+            Schema::from_toml(PUZZLE_SCHEMA).expect("puzzle schema should be valid")
+        })
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, SchemaError> {
+        Ok(toml::from_str(content)?)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, SchemaError> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    /// Load a .toml or .json file
+    pub fn load(path: &Path) -> Result<Self, SchemaError> {
+        let content = fs::read_to_string(path).map_err(|source| SchemaError::Read {
+            path: path.to_owned(),
+            source,
+        })?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Schema::from_toml(&content),
+            Some("json") => Schema::from_json(&content),
+            _ => Err(SchemaError::UnknownFormat {
+                path: path.to_owned(),
+            }),
+        }
+    }
+
+    pub fn with_field(mut self, id: impl Into<String>, rule: FieldRule) -> Self {
+        self.fields.insert(id.into(), rule);
+        self
+    }
+
+    pub fn rule(&self, id: &str) -> Option<&FieldRule> {
+        self.fields.get(id)
+    }
+
+    /// Fields with their rules, ordered by ID
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &FieldRule)> {
+        self.fields.iter().map(|(id, rule)| (id.as_str(), rule))
+    }
+
+    /// IDs of the fields every passport needs
+    pub fn required(&self) -> impl Iterator<Item = &str> {
+        self.fields()
+            .filter(|(_, rule)| rule.required)
+            .map(|(id, _)| id)
+    }
+
+    /// All required fields are present, the values are not checked
+    pub fn is_complete(&self, passport: &PassportParse) -> bool {
+        self.required().all(|id| passport.get(id).is_some())
+    }
+
+    /// Complete and every field with a rule has a valid value, fields without one are ignored
+    pub fn is_valid(&self, passport: &PassportParse) -> bool {
+        self.is_complete(passport)
+            && passport.fields.iter().all(|(field, value)| {
+                self.rule(field.id())
+                    .is_none_or(|rule| rule.kind.check(value).is_ok())
+            })
+    }
+}
+
+//##################
+
+// Rules
+//##################
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FieldRule {
+    #[serde(flatten)]
+    pub kind: FieldType,
+    #[serde(default)]
+    pub required: bool,
+}
+
+impl FieldRule {
+    pub fn new(kind: FieldType, required: bool) -> Self {
+        Self { kind, required }
+    }
+}
+
+/// Inclusive range of numbers
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Bounds {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Bounds {
+    pub fn check(&self, value: u32) -> Result<u32, InvalidValue> {
+        if (self.min..=self.max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(InvalidValue::OutOfRange {
+                value,
+                min: self.min,
+                max: self.max,
+            })
+        }
+    }
+}
+
+/// How the value of a field has to look
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FieldType {
+    /// Four digits between min and max
+    Year { min: u32, max: u32 },
+    /// Number followed by one of the units, each with its own range
+    Length { units: BTreeMap<String, Bounds> },
+    /// # followed by six lowercase hex digits
+    HexColor,
+    /// Exactly one of the values
+    OneOf { values: Vec<String> },
+    /// Exactly len digits, leading zeros included
+    Digits { len: usize },
+    /// Anything goes
+    Any,
+}
+
+impl FieldType {
+    pub fn check(&self, value: &str) -> Result<(), InvalidValue> {
+        match self {
+            FieldType::Year { min, max } => {
+                if value.len() != 4 {
+                    return Err(InvalidValue::NotAYear(value.to_owned()));
+                }
+                Bounds {
+                    min: *min,
+                    max: *max,
+                }
+                .check(parse_number(value)?)?;
+            }
+            FieldType::Length { units } => {
+                let (number, bounds) = units
+                    .iter()
+                    .find_map(|(unit, bounds)| Some((value.strip_suffix(unit.as_str())?, bounds)))
+                    .ok_or_else(|| {
+                        let units = units.keys().cloned().collect::<Vec<_>>();
+                        InvalidValue::UnknownUnit(units.join(", "))
+                    })?;
+                bounds.check(parse_number(number)?)?;
+            }
+            FieldType::HexColor => {
+                let hex = value.strip_prefix('#').unwrap_or_default();
+                if hex.len() != 6 || !hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')) {
+                    return Err(InvalidValue::NotAHexColor);
+                }
+            }
+            FieldType::OneOf { values } => {
+                if !values.iter().any(|v| v == value) {
+                    return Err(InvalidValue::NotOneOf(values.join(", ")));
+                }
+            }
+            FieldType::Digits { len } => {
+                if value.len() != *len || !value.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(InvalidValue::NotDigits(*len));
+                }
+            }
+            FieldType::Any => {}
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_number(value: &str) -> Result<u32, InvalidValue> {
+    value
+        .parse()
+        .map_err(|_| InvalidValue::NotANumber(value.to_owned()))
+}
+
+//##################
+
+pub mod error {
+    use std::io;
+    use std::path::PathBuf;
+
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    pub enum SchemaError {
+        #[error("can not read schema file {}", path.display())]
+        Read {
+            path: PathBuf,
+            #[source]
+            source: io::Error,
+        },
+
+        #[error("schema file {} is neither .toml nor .json", path.display())]
+        UnknownFormat { path: PathBuf },
+
+        #[error("malformed toml schema: {0}")]
+        Toml(#[from] toml::de::Error),
+
+        #[error("malformed json schema: {0}")]
+        Json(#[from] serde_json::Error),
+    }
+
+    /// Why a value does not follow the rule of its field
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum InvalidValue {
+        #[error("{0:?} is not a number")]
+        NotANumber(String),
+
+        #[error("{0:?} is not a four digit year")]
+        NotAYear(String),
+
+        #[error("{value} is not between {min} and {max}")]
+        OutOfRange { value: u32, min: u32, max: u32 },
+
+        #[error("{value} is larger than {max}")]
+        TooLarge { value: String, max: u32 },
+
+        #[error("unit missing, expected one of {0}")]
+        UnknownUnit(String),
+
+        #[error("not a # followed by six hex digits")]
+        NotAHexColor,
+
+        #[error("not one of {0}")]
+        NotOneOf(String),
+
+        #[error("not exactly {0} digits")]
+        NotDigits(usize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_rules() {
+        let schema = Schema::puzzle();
+        let check = |id: &str, value: &str| schema.rule(id).unwrap().kind.check(value);
+
+        assert_eq!(check("byr", "2002"), Ok(()));
+        assert!(check("byr", "2003").is_err());
+        assert_eq!(check("hgt", "60in"), Ok(()));
+        assert_eq!(check("hgt", "190cm"), Ok(()));
+        assert!(check("hgt", "190in").is_err());
+        assert_eq!(
+            check("hgt", "190"),
+            Err(InvalidValue::UnknownUnit("cm, in".to_owned()))
+        );
+        assert_eq!(check("hcl", "#123abc"), Ok(()));
+        assert!(check("hcl", "#123abz").is_err());
+        assert!(check("hcl", "123abc").is_err());
+        assert_eq!(check("ecl", "brn"), Ok(()));
+        assert!(check("ecl", "wat").is_err());
+        assert_eq!(check("pid", "000000001"), Ok(()));
+        assert!(check("pid", "0123456789").is_err());
+        assert_eq!(check("cid", "whatever"), Ok(()));
+
+        assert_eq!(
+            schema.required().collect::<Vec<_>>(),
+            vec!["byr", "ecl", "eyr", "hcl", "hgt", "iyr", "pid"]
+        );
+    }
+
+    #[test]
+    fn json() {
+        let schema = Schema::from_json(
+            r#"{ "fields": { "hgt": { "type": "length", "required": true,
+                "units": { "cm": { "min": 100, "max": 250 } } } } }"#,
+        )
+        .unwrap();
+
+        let expected = Schema::default().with_field(
+            "hgt",
+            FieldRule::new(
+                FieldType::Length {
+                    units: vec![("cm".to_owned(), Bounds { min: 100, max: 250 })]
+                        .into_iter()
+                        .collect(),
+                },
+                true,
+            ),
+        );
+        assert_eq!(schema, expected);
+        assert!(Schema::from_json(r#"{ "fields": { "byr": { "type": "date" } } }"#).is_err());
+    }
+}
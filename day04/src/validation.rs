@@ -0,0 +1,358 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+use crate::passport::PassportParse;
+use crate::schema::{InvalidValue, Schema};
+
+pub use error::ReportFormatError;
+
+// PassportReport
+//##################
+
+/// Everything wrong with a passport
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct PassportReport {
+    /// Position in the batch, starting at 1
+    pub passport: usize,
+    /// Line of the batch the passport starts at
+    pub line: usize,
+    /// No required field missing and no invalid value
+    pub valid: bool,
+    /// Required field IDs not present
+    pub missing: Vec<String>,
+    pub invalid: Vec<InvalidField>,
+    /// Field IDs present more than once
+    pub duplicates: Vec<String>,
+    /// Field IDs without a rule in the schema, each once
+    pub unknown: Vec<String>,
+}
+
+impl PassportReport {
+    /// Check all fields of passport, duplicates are checked each
+    pub fn check(passport_number: usize, passport: &PassportParse, schema: &Schema) -> Self {
+        let ids = passport
+            .fields
+            .iter()
+            .map(|(field, _)| field.id())
+            .collect::<Vec<_>>();
+
+        let missing = schema
+            .required()
+            .filter(|id| !ids.contains(id))
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+
+        let invalid = passport
+            .fields
+            .iter()
+            .filter_map(|(field, value)| {
+                let reason = schema.rule(field.id())?.kind.check(value).err()?;
+                Some(InvalidField {
+                    field: field.id().to_owned(),
+                    value: (*value).to_owned(),
+                    reason,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut duplicates = Vec::<String>::new();
+        let mut unknown = Vec::<String>::new();
+        for (idx, id) in ids.iter().enumerate() {
+            let first = !ids[..idx].contains(id);
+            if !first && !duplicates.iter().any(|dup| dup == id) {
+                duplicates.push((*id).to_owned());
+            }
+            if first && schema.rule(id).is_none() {
+                unknown.push((*id).to_owned());
+            }
+        }
+
+        Self {
+            passport: passport_number,
+            line: passport.line,
+            valid: missing.is_empty() && invalid.is_empty(),
+            missing,
+            invalid,
+            duplicates,
+            unknown,
+        }
+    }
+}
+
+/// Value that does not follow the rule of its field
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct InvalidField {
+    pub field: String,
+    pub value: String,
+    #[serde(serialize_with = "serialize_display")]
+    pub reason: InvalidValue,
+}
+
+/// Format: ID "VALUE": REASON
+impl fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:?}: {}", self.field, self.value, self.reason)
+    }
+}
+
+fn serialize_display<S: Serializer>(
+    value: &impl fmt::Display,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+//##################
+
+// ReportFormat
+//##################
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ReportFormat {
+    Table,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn write(self, reports: &[PassportReport], out: &mut impl Write) -> io::Result<()> {
+        match self {
+            ReportFormat::Table => write_table(reports, out),
+            ReportFormat::Json => {
+                serde_json::to_writer_pretty(&mut *out, reports)?;
+                writeln!(out)
+            }
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "table" => Ok(ReportFormat::Table),
+            "json" => Ok(ReportFormat::Json),
+            _ => Err(ReportFormatError::UnknownFormat(s.to_owned())),
+        }
+    }
+}
+
+const HEADER: [&str; 7] = [
+    "passport",
+    "line",
+    "status",
+    "missing",
+    "duplicates",
+    "unknown",
+    "invalid",
+];
+
+/// Column widths of streamed tables, wider cells shift the rest of their row
+const STREAMED_WIDTHS: [usize; 7] = [8, 8, 7, 24, 10, 10, 0];
+
+/// One row per passport, multiple entries in a cell are separated by "; "
+///
+/// Columns are as wide as their widest cell, so all reports are needed up front
+fn write_table(reports: &[PassportReport], out: &mut impl Write) -> io::Result<()> {
+    let rows = reports.iter().map(row).collect::<Vec<_>>();
+
+    let mut widths = HEADER.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    write_row(out, &HEADER, &widths)?;
+    for row in &rows {
+        write_row(out, row, &widths)?;
+    }
+
+    Ok(())
+}
+
+fn row(report: &PassportReport) -> [String; 7] {
+    let join = |values: &[String]| match values {
+        [] => "-".to_owned(),
+        values => values.join("; "),
+    };
+
+    let invalid = report
+        .invalid
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    [
+        report.passport.to_string(),
+        report.line.to_string(),
+        if report.valid { "valid" } else { "invalid" }.to_owned(),
+        join(&report.missing),
+        join(&report.duplicates),
+        join(&report.unknown),
+        join(&invalid),
+    ]
+}
+
+fn write_row(out: &mut impl Write, cells: &[impl AsRef<str>], widths: &[usize]) -> io::Result<()> {
+    let line = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:width$}", cell.as_ref(), width = width))
+        .collect::<Vec<_>>()
+        .join("  ");
+    writeln!(out, "{}", line.trim_end())
+}
+
+//##################
+
+// ReportWriter
+//##################
+
+/// Writes reports as they arrive, e.g. while streaming a huge batch
+///
+/// Tables get fixed column widths, json is written as one array
+pub struct ReportWriter<W> {
+    format: ReportFormat,
+    out: W,
+    written: usize,
+}
+
+impl<W: Write> ReportWriter<W> {
+    pub fn new(format: ReportFormat, out: W) -> Self {
+        Self {
+            format,
+            out,
+            written: 0,
+        }
+    }
+
+    pub fn write(&mut self, report: &PassportReport) -> io::Result<()> {
+        match self.format {
+            ReportFormat::Table => {
+                if self.written == 0 {
+                    write_row(&mut self.out, &HEADER, &STREAMED_WIDTHS)?;
+                }
+                write_row(&mut self.out, &row(report), &STREAMED_WIDTHS)?;
+            }
+            ReportFormat::Json => {
+                let separator = if self.written == 0 { "[\n  " } else { ",\n  " };
+                self.out.write_all(separator.as_bytes())?;
+                serde_json::to_writer(&mut self.out, report)?;
+            }
+        }
+
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Close the json array or write the header of an empty table
+    pub fn finish(mut self) -> io::Result<()> {
+        match (self.format, self.written) {
+            (ReportFormat::Table, 0) => write_row(&mut self.out, &HEADER, &STREAMED_WIDTHS)?,
+            (ReportFormat::Table, _) => {}
+            (ReportFormat::Json, 0) => writeln!(self.out, "[]")?,
+            (ReportFormat::Json, _) => writeln!(self.out, "\n]")?,
+        }
+
+        self.out.flush()
+    }
+}
+
+//##################
+
+pub mod error {
+    use thiserror::Error;
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum ReportFormatError {
+        #[error("unknown report format \"{0}\", expected one of: table, json")]
+        UnknownFormat(String),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_failures() {
+        let passport = PassportParse::from(
+            "eyr:1972 cid:100 hcl:#18171d hcl:#18171d hcl:#18171d ecl:amb hgt:170 foo:bar iyr:2018 foo:baz byr:1926",
+        );
+        let report = PassportReport::check(1, &passport, Schema::puzzle());
+
+        assert!(!report.valid);
+        assert_eq!(report.missing, vec!["pid"]);
+        assert_eq!(
+            report
+                .invalid
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "eyr \"1972\": 1972 is not between 2020 and 2030",
+                "hgt \"170\": unit missing, expected one of cm, in",
+            ]
+        );
+        assert_eq!(report.duplicates, vec!["hcl", "foo"]);
+        assert_eq!(report.unknown, vec!["foo"]);
+    }
+
+    #[test]
+    fn table() {
+        let passport = PassportParse::from("byr:1920 ecl:brn");
+        let report = PassportReport::check(7, &passport, Schema::puzzle());
+
+        let mut out = Vec::new();
+        ReportFormat::Table
+            .write(std::slice::from_ref(&report), &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+passport  line  status   missing                  duplicates  unknown  invalid
+7         1     invalid  eyr; hcl; hgt; iyr; pid  -           -        -
+"
+        );
+
+        let mut out = Vec::new();
+        let mut writer = ReportWriter::new(ReportFormat::Table, &mut out);
+        writer.write(&report).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+passport  line      status   missing                   duplicates  unknown     invalid
+7         1         invalid  eyr; hcl; hgt; iyr; pid   -           -           -
+"
+        );
+    }
+
+    #[test]
+    fn streamed_json() {
+        let reports = ["byr:1920 ecl:brn", "foo:bar"]
+            .iter()
+            .map(|passport| {
+                PassportReport::check(1, &PassportParse::from(*passport), Schema::puzzle())
+            })
+            .collect::<Vec<_>>();
+
+        let mut out = Vec::new();
+        let mut writer = ReportWriter::new(ReportFormat::Json, &mut out);
+        for report in &reports {
+            writer.write(report).unwrap();
+        }
+        writer.finish().unwrap();
+        let streamed: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(streamed, serde_json::to_value(&reports).unwrap());
+
+        let mut empty = Vec::new();
+        ReportWriter::new(ReportFormat::Json, &mut empty)
+            .finish()
+            .unwrap();
+        assert_eq!(empty, b"[]\n");
+    }
+}
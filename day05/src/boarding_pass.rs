@@ -1,12 +1,14 @@
-use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
+use crate::codec::SeatCodec;
+
 pub use error::SeatParseError;
 
 // Seat
 //##################
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Seat {
     row: usize,
     column: usize,
@@ -17,70 +19,51 @@ impl Seat {
         Self { row, column }
     }
 
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Seat ID of the puzzle's plane
     pub fn get_id(&self) -> usize {
-        self.row * 8 + self.column
+        SeatCodec::PUZZLE.seat_id(*self)
     }
 }
 
+/// Boarding pass code of the puzzle, e.g. FBFBBFFRLR
 impl FromStr for Seat {
     type Err = error::SeatParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // expected Format: RRRRRRRCCC
-        // with R : Row -> F (Front), B (Back)
-        // and C: Column -> L (Left), R (Right)
-        // eg. FBFBBFBLLR
-
-        use error::SeatParseError::*;
-
-        // there has to be 10 Chars
-        if s.chars().count() != 10 {
-            return Err(MalformedInput);
-        }
+        SeatCodec::PUZZLE.decode(s)
+    }
+}
 
-        // take the first 7 chars, parse them to RowSelect (F or B), pull unknown chars out
-        // and report that error if it happens
-        let row_info = s
-            .chars()
-            .take(7)
-            .map(RowSelect::try_from)
-            .collect::<Result<Vec<_>, _>>();
-        let row_info = match row_info {
-            Ok(values) => values,
-            Err(e) => return Err(UnknownRowChar(e.get_char())),
-        };
-
-        // use gained row info to binary search row between 0 and 127
-        let row = binary_search(&row_info, 0, 127);
-
-        // skip first 7 chars, take the next 3, parse them to ColumnSelect (L or R), pull unknown chars out
-        // and report that error if it happens
-        let column_info = s
-            .chars()
-            .skip(7)
-            .take(3)
-            .map(ColumnSelect::try_from)
-            .collect::<Result<Vec<_>, _>>();
-        let column_info = match column_info {
-            Ok(values) => values,
-            Err(e) => return Err(UnknownColumnChar(e.get_char())),
-        };
-
-        // use gained row info to binary search row between 0 and 7
-        let column = binary_search(&column_info, 0, 7);
-
-        Ok(Seat::new(row, column))
+/// Format: row ROW, column COLUMN
+///
+/// Works for any seat, SeatCodec::encode gives the boarding pass code
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}, column {}", self.row, self.column)
     }
 }
 
 //##################
 
-fn binary_search<C: BinarySearchHelper>(directives: &[C], min: usize, max: usize) -> usize {
+/// Narrow min..=max down to one value, halving it per directive
+pub(crate) fn binary_search<C: BinarySearchHelper>(
+    directives: &[C],
+    min: usize,
+    max: usize,
+) -> usize {
     let mut min = min;
     let mut max = max;
 
     for directive in directives {
-        let half = (min + max + 1) / 2;
+        let half = (min + max).div_ceil(2);
 
         if directive.is_lower() {
             // min = min
@@ -96,70 +79,6 @@ fn binary_search<C: BinarySearchHelper>(directives: &[C], min: usize, max: usize
     min
 }
 
-// RowSelect
-//##################
-
-#[derive(Debug, Eq, PartialEq)]
-pub enum RowSelect {
-    Front,
-    Back,
-}
-
-impl TryFrom<char> for RowSelect {
-    type Error = error::IdentifierParseError;
-
-    fn try_from(c: char) -> Result<Self, Self::Error> {
-        use error::IdentifierParseError;
-        use RowSelect::*;
-
-        match c {
-            'F' => Ok(Front),
-            'B' => Ok(Back),
-            x => Err(IdentifierParseError::new(x)),
-        }
-    }
-}
-
-impl BinarySearchHelper for RowSelect {
-    fn is_lower(&self) -> bool {
-        *self == RowSelect::Front
-    }
-}
-
-//##################
-
-// ColumnSelect
-//##################
-
-#[derive(Debug, Eq, PartialEq)]
-pub enum ColumnSelect {
-    Left,
-    Right,
-}
-
-impl TryFrom<char> for ColumnSelect {
-    type Error = error::IdentifierParseError;
-
-    fn try_from(c: char) -> Result<Self, Self::Error> {
-        use error::IdentifierParseError;
-        use ColumnSelect::*;
-
-        match c {
-            'L' => Ok(Left),
-            'R' => Ok(Right),
-            x => Err(IdentifierParseError::new(x)),
-        }
-    }
-}
-
-impl BinarySearchHelper for ColumnSelect {
-    fn is_lower(&self) -> bool {
-        *self == ColumnSelect::Left
-    }
-}
-
-//##################
-
 pub trait BinarySearchHelper {
     fn is_lower(&self) -> bool;
 
@@ -171,22 +90,6 @@ pub trait BinarySearchHelper {
 pub mod error {
     use thiserror::Error;
 
-    #[derive(Error, Debug)]
-    #[error("unknown char '{unknown_char}'")]
-    pub struct IdentifierParseError {
-        unknown_char: char,
-    }
-
-    impl IdentifierParseError {
-        pub fn new(unknown_char: char) -> Self {
-            Self { unknown_char }
-        }
-
-        pub fn get_char(&self) -> char {
-            self.unknown_char
-        }
-    }
-
     #[derive(Error, Debug)]
     pub enum SeatParseError {
         #[error("unknown row char '{0}'")]
@@ -195,7 +98,40 @@ pub mod error {
         #[error("unknown column char '{0}'")]
         UnknownColumnChar(char),
 
-        #[error("malformed input")]
-        MalformedInput,
+        #[error("expected {expected} chars, found {found}")]
+        WrongLength { expected: usize, found: usize },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn seat() -> impl Strategy<Value = Seat> {
+        (0..128_usize, 0..8_usize).prop_map(|(row, column)| Seat::new(row, column))
+    }
+
+    #[test]
+    fn example() {
+        let seat = "FBFBBFFRLR".parse::<Seat>().unwrap();
+        assert_eq!(seat, Seat::new(44, 5));
+        assert_eq!(SeatCodec::PUZZLE.encode(seat).unwrap(), "FBFBBFFRLR");
+        assert_eq!(seat.to_string(), "row 44, column 5");
+    }
+
+    #[test]
+    fn outside_plane() {
+        let seat = Seat::new(200, 0);
+        assert_eq!(seat.to_string(), "row 200, column 0");
+        assert!(SeatCodec::PUZZLE.encode(seat).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn code_round_trip(seat in seat()) {
+            let code = SeatCodec::PUZZLE.encode(seat).unwrap();
+            prop_assert_eq!(code.parse::<Seat>().unwrap(), seat);
+        }
     }
 }
@@ -0,0 +1,357 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::boarding_pass::{binary_search, BinarySearchHelper, Seat, SeatParseError};
+
+pub use error::CodecError;
+
+// Alphabet
+//##################
+
+/// Letters choosing the lower or the upper half, e.g. F and B for rows
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Alphabet {
+    pub lower: char,
+    pub upper: char,
+}
+
+impl Alphabet {
+    pub const fn new(lower: char, upper: char) -> Self {
+        Self { lower, upper }
+    }
+
+    fn half(&self, c: char) -> Option<Half> {
+        match c {
+            c if c == self.lower => Some(Half::Lower),
+            c if c == self.upper => Some(Half::Upper),
+            _ => None,
+        }
+    }
+
+    fn letter(&self, bit: bool) -> char {
+        if bit {
+            self.upper
+        } else {
+            self.lower
+        }
+    }
+}
+
+/// Parse alphabet from two letters, lower half first, e.g. "FB"
+impl FromStr for Alphabet {
+    type Err = CodecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(lower), Some(upper), None) => Ok(Alphabet::new(lower, upper)),
+            _ => Err(CodecError::MalformedAlphabet(s.to_owned())),
+        }
+    }
+}
+
+/// Format: LOWERUPPER
+impl fmt::Display for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.lower, self.upper)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Half {
+    Lower,
+    Upper,
+}
+
+impl BinarySearchHelper for Half {
+    fn is_lower(&self) -> bool {
+        *self == Half::Lower
+    }
+}
+
+//##################
+
+// SeatCodec
+//##################
+
+/// Boarding pass codes with any number of row and column letters
+///
+/// Each letter halves the remaining rows or columns, so a code is the seat ID
+/// written in binary with the alphabets as digits
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SeatCodec {
+    row_bits: u32,
+    column_bits: u32,
+    row: Alphabet,
+    column: Alphabet,
+}
+
+impl SeatCodec {
+    /// 128 rows with F and B, 8 columns with L and R
+    pub const PUZZLE: SeatCodec = SeatCodec {
+        row_bits: 7,
+        column_bits: 3,
+        row: Alphabet::new('F', 'B'),
+        column: Alphabet::new('L', 'R'),
+    };
+
+    /// Every seat ID has to fit into a usize and each alphabet needs two different letters
+    pub fn new(
+        row_bits: u32,
+        column_bits: u32,
+        row: Alphabet,
+        column: Alphabet,
+    ) -> Result<Self, CodecError> {
+        let fits = row_bits
+            .checked_add(column_bits)
+            .is_some_and(|bits| bits < usize::BITS);
+        if !fits {
+            return Err(CodecError::TooManyBits {
+                row_bits,
+                column_bits,
+            });
+        }
+        for alphabet in [row, column] {
+            if alphabet.lower == alphabet.upper {
+                return Err(CodecError::SameLetters(alphabet.lower));
+            }
+        }
+
+        Ok(Self {
+            row_bits,
+            column_bits,
+            row,
+            column,
+        })
+    }
+
+    pub fn row_bits(&self) -> u32 {
+        self.row_bits
+    }
+
+    pub fn column_bits(&self) -> u32 {
+        self.column_bits
+    }
+
+    pub fn row_alphabet(&self) -> Alphabet {
+        self.row
+    }
+
+    pub fn column_alphabet(&self) -> Alphabet {
+        self.column
+    }
+
+    pub fn rows(&self) -> usize {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> usize {
+        1 << self.column_bits
+    }
+
+    /// Letters of a code
+    pub fn code_len(&self) -> usize {
+        (self.row_bits + self.column_bits) as usize
+    }
+
+    /// Row times the number of columns plus column
+    pub fn seat_id(&self, seat: Seat) -> usize {
+        seat.row() << self.column_bits | seat.column()
+    }
+
+    /// Narrow down row and column half by half
+    pub fn decode(&self, code: &str) -> Result<Seat, SeatParseError> {
+        let (rows, columns) = self.halves(code)?;
+
+        let row = binary_search(&rows, 0, self.rows() - 1);
+        let column = binary_search(&columns, 0, self.columns() - 1);
+
+        Ok(Seat::new(row, column))
+    }
+
+    /// Shortcut of decode, reading the code as binary number of the seat ID
+    pub fn decode_id(&self, code: &str) -> Result<usize, SeatParseError> {
+        let (rows, columns) = self.halves(code)?;
+
+        Ok(rows
+            .iter()
+            .chain(&columns)
+            .fold(0, |id, half| id << 1 | half.is_upper() as usize))
+    }
+
+    /// Back to the code, fails for seats outside the plane
+    pub fn encode(&self, seat: Seat) -> Result<String, CodecError> {
+        if seat.row() >= self.rows() || seat.column() >= self.columns() {
+            return Err(CodecError::SeatOutOfRange {
+                row: seat.row(),
+                column: seat.column(),
+                rows: self.rows(),
+                columns: self.columns(),
+            });
+        }
+
+        Ok(self.letters(self.seat_id(seat)))
+    }
+
+    /// Shortcut of encode, writing the seat ID as binary number
+    pub fn encode_id(&self, id: usize) -> Result<String, CodecError> {
+        let seats = self.rows() * self.columns();
+        if id >= seats {
+            return Err(CodecError::IdOutOfRange { id, seats });
+        }
+
+        Ok(self.letters(id))
+    }
+
+    /// Highest bit first, the row bits with the row alphabet
+    fn letters(&self, id: usize) -> String {
+        (0..self.code_len() as u32)
+            .rev()
+            .map(|bit| {
+                let alphabet = if bit >= self.column_bits {
+                    self.row
+                } else {
+                    self.column
+                };
+                alphabet.letter(id >> bit & 1 == 1)
+            })
+            .collect()
+    }
+
+    /// Row and column halves of code, checking length and letters
+    fn halves(&self, code: &str) -> Result<(Vec<Half>, Vec<Half>), SeatParseError> {
+        use SeatParseError::*;
+
+        let found = code.chars().count();
+        if found != self.code_len() {
+            return Err(WrongLength {
+                expected: self.code_len(),
+                found,
+            });
+        }
+
+        let (row, column) = code.split_at(
+            code.char_indices()
+                .nth(self.row_bits as usize)
+                .map_or(code.len(), |(idx, _)| idx),
+        );
+        let rows = row
+            .chars()
+            .map(|c| self.row.half(c).ok_or(UnknownRowChar(c)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let columns = column
+            .chars()
+            .map(|c| self.column.half(c).ok_or(UnknownColumnChar(c)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((rows, columns))
+    }
+}
+
+impl Default for SeatCodec {
+    fn default() -> Self {
+        SeatCodec::PUZZLE
+    }
+}
+
+//##################
+
+pub mod error {
+    use thiserror::Error;
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum CodecError {
+        #[error("{row_bits} row and {column_bits} column bits do not fit into a seat id")]
+        TooManyBits { row_bits: u32, column_bits: u32 },
+
+        #[error("alphabet uses '{0}' for both halves")]
+        SameLetters(char),
+
+        #[error("alphabet \"{0}\" malformed, expected two letters")]
+        MalformedAlphabet(String),
+
+        #[error("seat at row {row}, column {column} outside of {rows} rows and {columns} columns")]
+        SeatOutOfRange {
+            row: usize,
+            column: usize,
+            rows: usize,
+            columns: usize,
+        },
+
+        #[error("seat id {id} outside of {seats} seats")]
+        IdOutOfRange { id: usize, seats: usize },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn codec() -> impl Strategy<Value = SeatCodec> {
+        (0..12_u32, 0..12_u32).prop_map(|(row_bits, column_bits)| {
+            SeatCodec::new(
+                row_bits,
+                column_bits,
+                Alphabet::new('U', 'D'),
+                Alphabet::new('<', '>'),
+            )
+            .unwrap()
+        })
+    }
+
+    #[test]
+    fn custom() {
+        let codec = SeatCodec::new(2, 4, Alphabet::new('a', 'b'), Alphabet::new('0', '1')).unwrap();
+
+        assert_eq!(codec.decode("ba0110").unwrap(), Seat::new(2, 6));
+        assert_eq!(codec.decode_id("ba0110").unwrap(), 38);
+        assert_eq!(codec.encode(Seat::new(2, 6)).unwrap(), "ba0110");
+        assert_eq!(codec.encode_id(38).unwrap(), "ba0110");
+
+        assert!(matches!(
+            codec.decode("b0110"),
+            Err(SeatParseError::WrongLength {
+                expected: 6,
+                found: 5
+            })
+        ));
+        assert!(matches!(
+            codec.decode("bc0110"),
+            Err(SeatParseError::UnknownRowChar('c'))
+        ));
+        assert!(codec.encode(Seat::new(4, 0)).is_err());
+        assert!(codec.encode_id(64).is_err());
+        assert_eq!(
+            SeatCodec::new(1, 1, Alphabet::new('x', 'x'), Alphabet::new('L', 'R')),
+            Err(CodecError::SameLetters('x'))
+        );
+        assert_eq!(
+            SeatCodec::new(
+                u32::MAX,
+                1,
+                Alphabet::new('F', 'B'),
+                Alphabet::new('L', 'R')
+            ),
+            Err(CodecError::TooManyBits {
+                row_bits: u32::MAX,
+                column_bits: 1
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip((codec, row, column) in codec().prop_flat_map(|codec| {
+            (Just(codec), 0..codec.rows(), 0..codec.columns())
+        })) {
+            let seat = Seat::new(row, column);
+            let id = codec.seat_id(seat);
+
+            let code = codec.encode(seat).unwrap();
+            prop_assert_eq!(codec.encode_id(id).unwrap(), code.clone());
+            prop_assert_eq!(codec.decode(&code).unwrap(), seat);
+            prop_assert_eq!(codec.decode_id(&code).unwrap(), id);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+pub mod boarding_pass;
+pub mod codec;
+
+use common::diagnostic::{parse_lines, Diagnostic};
+
+use codec::SeatCodec;
+
+pub use error::SeatError;
+
+/// Seat ids of all boarding passes, sorted
+pub type Input = Vec<usize>;
+
+pub type Error = Diagnostic;
+
+/// Parse one boarding pass per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    parse_with(input, &SeatCodec::PUZZLE)
+}
+
+/// Parse one boarding pass per line, coded like codec expects
+pub fn parse_with(input: &str, codec: &SeatCodec) -> Result<Input, Error> {
+    get_seats(input, codec)
+}
+
+/// Highest seat id, or 0 without any boarding pass
+pub fn part1(input: &Input) -> usize {
+    input.iter().max().copied().unwrap_or(0)
+}
+
+/// The only empty seat with both neighbors taken
+pub fn part2(input: &Input) -> Result<usize, SeatError> {
+    get_empty_seat(input)
+}
+
+pub struct Day05;
+
+impl common::Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input)?)
+    }
+}
+
+/// returns seat ids as sorted list
+fn get_seats(content: &str, codec: &SeatCodec) -> Result<Vec<usize>, Error> {
+    // each code is the binary number of its seat id
+    let mut seats = parse_lines(content, |line| codec.decode_id(line))?;
+
+    seats.sort_unstable();
+
+    Ok(seats)
+}
+
+fn get_empty_seat(seats: &[usize]) -> Result<usize, SeatError> {
+    // seats are sorted, so an empty seat with both neighbors taken
+    // is a gap of exactly one between two consecutive ids
+
+    if seats.is_empty() {
+        return Err(SeatError::NoSeats);
+    }
+
+    let empty_seats = seats
+        .windows(2)
+        .filter(|pair| pair[1] - pair[0] == 2)
+        .map(|pair| pair[0] + 1)
+        .collect::<Vec<_>>();
+
+    match empty_seats.as_slice() {
+        [seat] => Ok(*seat),
+        [] => Err(SeatError::NoEmptySeat),
+        seats => Err(SeatError::SeveralEmptySeats(seats.to_vec())),
+    }
+}
+
+pub mod error {
+    use thiserror::Error;
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum SeatError {
+        #[error("no boarding passes")]
+        NoSeats,
+
+        #[error("no empty seat with both neighbors taken")]
+        NoEmptySeat,
+
+        #[error("several empty seats with both neighbors taken: {0:?}")]
+        SeveralEmptySeats(Vec<usize>),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day05,
+        example_1: common::example_input!("1") => { part1: 820 },
+    }
+
+    #[test]
+    fn empty_seat() {
+        assert_eq!(part2(&vec![3, 4, 6, 7]), Ok(5));
+        assert_eq!(part2(&vec![]), Err(SeatError::NoSeats));
+        assert_eq!(part2(&vec![0, 3]), Err(SeatError::NoEmptySeat));
+        assert_eq!(
+            part2(&vec![1, 3, 5]),
+            Err(SeatError::SeveralEmptySeats(vec![2, 4]))
+        );
+    }
+}
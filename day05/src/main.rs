@@ -1,69 +1,88 @@
-mod boarding_pass;
-
-use std::collections::HashSet;
-use std::fs;
-
-use boarding_pass::Seat;
+use clap::Clap;
+
+use common::report::{Part, PartResult, Report};
+use common::Solution;
+use day05::codec::{Alphabet, SeatCodec};
+use day05::Day05;
+
+#[derive(Clap)]
+#[clap(
+    version = "1.0",
+    author = "Michael Mario Kubicki <contact@michael-kubicki.de>"
+)]
+struct Opts {
+    #[clap(flatten)]
+    common: common::simple_cli::Opts,
+
+    // --bench only times the puzzle's codec, defaults are applied in main
+    // because clap counts a default_value as given and would always conflict
+    /// Letters choosing the row, the plane has 2^BITS rows [default: 7]
+    #[clap(long, value_name = "BITS", conflicts_with = "bench")]
+    row_bits: Option<u32>,
+
+    /// Letters choosing the column, the plane has 2^BITS columns [default: 3]
+    #[clap(long, value_name = "BITS", conflicts_with = "bench")]
+    column_bits: Option<u32>,
+
+    /// Row letters, front half first [default: FB]
+    #[clap(long, value_name = "LETTERS", conflicts_with = "bench")]
+    row_letters: Option<Alphabet>,
+
+    /// Column letters, left half first [default: LR]
+    #[clap(long, value_name = "LETTERS", conflicts_with = "bench")]
+    column_letters: Option<Alphabet>,
+
+    /// Print the boarding pass code of the seat id instead of solving, can be repeated
+    #[clap(long, value_name = "ID", number_of_values = 1)]
+    encode: Vec<usize>,
+}
 
 fn main() -> anyhow::Result<()> {
-    let options = common::simple_cli::Opts::get();
-
-    let content = fs::read_to_string(options.input)?;
-
-    let seats = get_seats(&content)?;
-
-    // Part 1
-    let highest_seat_id = seats.iter().max().unwrap_or(&0);
-    println!("Highest seat id: {}", highest_seat_id);
-
-    // Part 2
-    let empty_seat = get_empty_seat(&seats);
-    println!("Empty seat: {}", empty_seat);
+    let options = Opts::parse();
+
+    let puzzle = SeatCodec::PUZZLE;
+    let codec = SeatCodec::new(
+        options.row_bits.unwrap_or(puzzle.row_bits()),
+        options.column_bits.unwrap_or(puzzle.column_bits()),
+        options.row_letters.unwrap_or(puzzle.row_alphabet()),
+        options.column_letters.unwrap_or(puzzle.column_alphabet()),
+    )?;
+
+    if !options.encode.is_empty() {
+        for id in &options.encode {
+            println!("{}: {}", id, codec.encode_id(*id)?);
+        }
+        return Ok(());
+    }
+
+    let content = common::input::load(&options.common.source_for::<Day05>()?)?;
+
+    if let Some(runs) = options.common.bench {
+        let bench = common::bench::bench::<Day05>(&content, runs)?;
+        options.common.format.print_bench(&[bench])?;
+        return Ok(());
+    }
+
+    let report = if codec == SeatCodec::PUZZLE {
+        common::solution::solve::<Day05>(&content)?
+    } else {
+        codec_report(&codec, &content)?
+    }
+    .with_label(Part::One, "Highest seat id")
+    .with_label(Part::Two, "Empty seat");
+
+    options.common.format.print(&[report])?;
 
     Ok(())
 }
 
-/// returns seat ids as sorted list
-fn get_seats(content: &str) -> anyhow::Result<Vec<usize>> {
-    // parse each line to Seat and map successful parses to seat id
-    let mut seats = content
-        .lines()
-        .map(str::parse::<Seat>)
-        .map(|parse_result| match parse_result {
-            Ok(value) => Ok(value.get_id()),
-            Err(e) => Err(e),
-        })
-        .collect::<Result<Vec<usize>, _>>()?;
-
-    seats.sort_unstable();
-
-    Ok(seats)
-}
-
-fn get_empty_seat(seats: &[usize]) -> usize {
-    // take smallest and largest seat id
-    // generate a hashset containing all ids from smallest to largest
-    // get differences between given and generated ids. should result to ids missing in given list
-    // check that ids next to missing id is given.
-
-    let first = *seats.first().unwrap();
-    let last = *seats.last().unwrap();
-
-    let control_group = (first..last).collect::<HashSet<_>>();
-    let seats = seats.iter().copied().collect::<HashSet<_>>();
-
-    let empty_seat = control_group
-        .difference(&seats)
-        .filter_map(|v| {
-            if seats.contains(&(v - 1)) && seats.contains(&(v + 1)) {
-                Some(*v)
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<_>>();
-
-    assert_eq!(empty_seat.len(), 1);
+/// Both parts with boarding passes coded like codec expects
+fn codec_report(codec: &SeatCodec, content: &str) -> anyhow::Result<Report> {
+    let input = day05::parse_with(content, codec)?;
 
-    empty_seat[0]
+    Ok(Report::new(Day05::DAY)
+        .with_part(PartResult::timed(Part::One, || day05::part1(&input)))
+        .with_part(PartResult::try_timed(Part::Two, || {
+            Ok(day05::part2(&input)?)
+        })?))
 }
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+/// Answers per person, grouped
+pub type Input = Vec<Vec<HashSet<char>>>;
+
+/// Parsing never fails, every char counts as an answer
+pub type Error = std::convert::Infallible;
+
+/// Parse groups separated by empty lines, one person per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    Ok(get_groups(input))
+}
+
+/// Sum of questions anyone in a group answered
+pub fn part1(input: &Input) -> usize {
+    input.iter().map(|group| count_union(group)).sum()
+}
+
+/// Sum of questions everyone in a group answered
+pub fn part2(input: &Input) -> usize {
+    input.iter().map(|group| count_intersection(group)).sum()
+}
+
+pub struct Day06;
+
+impl common::Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+fn get_groups(content: &str) -> Vec<Vec<HashSet<char>>> {
+    // groups delimited by empty line -> \n\n split
+    // answers of people in group one per line -> lines()
+    // answers = string of chars -> collect as HashSet<char>
+    // collect answers in group as Vec
+    // collect groups as Vec
+
+    content
+        .split("\n\n")
+        .map(|group| {
+            group
+                .lines()
+                .map(|answers| answers.chars().collect())
+                .collect()
+        })
+        .collect()
+}
+
+fn count_union(set: &[HashSet<char>]) -> usize {
+    // use op_on_set with union of sets
+    op_on_set(set, |acc, next_set| &acc | next_set)
+}
+
+fn count_intersection(set: &[HashSet<char>]) -> usize {
+    // use op_on_set with intersection of sets
+    op_on_set(set, |acc, next_set| &acc & next_set)
+}
+
+fn op_on_set(
+    set: &[HashSet<char>],
+    op: impl Fn(HashSet<char>, &HashSet<char>) -> HashSet<char>,
+) -> usize {
+    //take clone of first element as accumulator and fold on it with op
+
+    let mut iter = set.iter();
+    iter.next()
+        .map(|first| iter.fold(first.clone(), op).len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day06,
+        example_1: common::example_input!("1") => { part1: 11, part2: 6 },
+    }
+}
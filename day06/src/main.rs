@@ -1,60 +1,22 @@
-use std::collections::HashSet;
-use std::fs;
+use common::report::Part;
+use day06::Day06;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.source_for::<Day06>()?)?;
 
-    let groups = get_groups(&content);
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day06>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // part 1
-    let union_count = groups.iter().map(count_union).sum::<usize>();
-    println!("Part 1 sum: {}", union_count);
+    let report = common::solution::solve::<Day06>(&content)?
+        .with_label(Part::One, "Part 1 sum")
+        .with_label(Part::Two, "Part 2 sum");
 
-    // part 2
-    let intersection_count = groups.iter().map(count_intersection).sum::<usize>();
-    println!("Part 2 sum: {}", intersection_count);
+    options.format.print(&[report])?;
 
     Ok(())
 }
-
-fn get_groups(content: &str) -> Vec<Vec<HashSet<char>>> {
-    // groups delimited by empty line -> \n\n split
-    // answers of people in group one per line -> lines()
-    // answers = string of chars -> collect as HashSet<char>
-    // collect answers in group as Vec
-    // collect groups as Vec
-
-    content
-        .split("\n\n")
-        .map(|group| {
-            group
-                .lines()
-                .map(|answers| answers.chars().collect())
-                .collect()
-        })
-        .collect()
-}
-
-fn count_union(set: &Vec<HashSet<char>>) -> usize {
-    // use op_on_set with union of sets
-    op_on_set(set, |acc, next_set| &acc | next_set)
-}
-
-fn count_intersection(set: &Vec<HashSet<char>>) -> usize {
-    // use op_on_set with intersection of sets
-    op_on_set(set, |acc, next_set| &acc & next_set)
-}
-
-fn op_on_set(
-    set: &Vec<HashSet<char>>,
-    op: impl Fn(HashSet<char>, &HashSet<char>) -> HashSet<char>,
-) -> usize {
-    //take clone of first element as accumulator and fold on it with op
-
-    let mut iter = set.iter();
-    iter.next()
-        .map(|first| iter.fold(first.clone(), op).len())
-        .unwrap_or(0)
-}
@@ -0,0 +1,76 @@
+pub mod rules;
+
+use common::diagnostic::Diagnostic;
+
+use rules::{BagInfo, Rule, Rules};
+
+/// Bag all questions are about as (adjective, color)
+pub const GOLD_BAG: (&str, &str) = ("shiny", "gold");
+
+/// Rules what bags contain
+pub type Input = Rules;
+
+pub type Error = Diagnostic;
+
+/// Parse one rule per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse::<Rule>().map_err(|err| {
+                let fragment = err.fragment().to_owned();
+                Diagnostic::at_line(input, index + 1, err.to_string())
+                    .narrow_to(&fragment)
+                    .with_error(err)
+            })
+        })
+        .collect()
+}
+
+/// Count of bags that can eventually contain a shiny gold bag
+pub fn part1(input: &Input) -> usize {
+    input.can_contain(&gold_bag()).len()
+}
+
+/// Count of bags inside a single shiny gold bag
+pub fn part2(input: &Input) -> usize {
+    input.get_contents_bag_count(&gold_bag())
+}
+
+pub struct Day07;
+
+impl common::Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+fn gold_bag() -> BagInfo {
+    BagInfo::new(GOLD_BAG.0, GOLD_BAG.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day07,
+        example_1: common::example_input!("1") => { part1: 4, part2: 32 },
+        example_2: common::example_input!("2") => { part2: 126 },
+    }
+}
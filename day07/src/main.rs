@@ -1,29 +1,25 @@
-mod rules;
-
-use std::fs;
-
-use rules::{BagInfo, Rules};
+use common::report::Part;
+use day07::Day07;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
-
-    let rules = Rules::from(content);
+    let content = common::input::load(&options.source_for::<Day07>()?)?;
 
-    let gold_bag = BagInfo::from("shiny gold");
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day07>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // part 1
-    let can_contain = rules.can_contain(&gold_bag);
-    println!(
-        "Bags that can contain at least one {}: {}",
-        gold_bag,
-        can_contain.len()
-    );
+    let report = common::solution::solve::<Day07>(&content)?
+        .with_label(
+            Part::One,
+            "Bags that can contain at least one [shiny gold bag]",
+        )
+        .with_label(Part::Two, "Bags needed in a single [shiny gold bag]");
 
-    // part 2
-    let count = rules.get_contents_bag_count(&gold_bag);
-    println!("Bags needed in a single {}: {}", gold_bag, count);
+    options.format.print(&[report])?;
 
     Ok(())
 }
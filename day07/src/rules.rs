@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::iter::FromIterator;
+use std::str::FromStr;
+
+use error::ParseRuleError;
 
 // Rules
 //##################
@@ -60,28 +63,18 @@ impl Rules {
                 .sum()
         });
 
-        match result {
-            Some(value) => value,
-            None => 0,
-        }
+        result.unwrap_or_default()
     }
 }
 
-impl<T: AsRef<str>> From<T> for Rules {
-    fn from(s: T) -> Self {
-        let s = s.as_ref();
-
-        // one rule per line
-        // parse line individually and collect to HashMap
-        //
-        // basic overview
-        // [ADJECTIVE] [COLOR] bags contain {[COUNT] [ADJECTIVE] [COLOR] bag{s}{, }}.
-
-        let rules = s
-            .lines()
-            .map(Rule::from)
-            .map(Rule::deconstruct)
-            .collect::<HashMap<_, _>>();
+// one rule per line
+// parse line individually and collect to HashMap
+//
+// basic overview
+// [ADJECTIVE] [COLOR] bags contain {[COUNT] [ADJECTIVE] [COLOR] bag{s}{, }}.
+impl FromIterator<Rule> for Rules {
+    fn from_iter<T: IntoIterator<Item = Rule>>(iter: T) -> Self {
+        let rules = iter.into_iter().map(Rule::deconstruct).collect();
 
         Rules { rules }
     }
@@ -126,10 +119,10 @@ impl Rule {
     }
 }
 
-impl<T: AsRef<str>> From<T> for Rule {
-    fn from(s: T) -> Self {
-        let s = s.as_ref();
+impl FromStr for Rule {
+    type Err = ParseRuleError;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         // Rule format:
         // [BAG PROPERTIES] contain {[BAG COUNT], }.
         //
@@ -138,19 +131,22 @@ impl<T: AsRef<str>> From<T> for Rule {
         // parse first as BagInfo
         // split second by ", " to separate and then parse bag counts
 
-        let stripped = s.strip_suffix('.').unwrap();
+        let stripped = s
+            .strip_suffix('.')
+            .ok_or_else(|| ParseRuleError::MissingPeriod(s.to_owned()))?;
+
+        let (key, contents) = stripped
+            .split_once("contain ")
+            .ok_or_else(|| ParseRuleError::MissingContain(s.to_owned()))?;
 
-        let mut iter = stripped.split("contain ");
-        let key = iter.next().unwrap().into();
+        let key = key.parse()?;
 
-        let value = iter
-            .next()
-            .unwrap()
+        let value = contents
             .split(", ")
-            .map(BagCount::from)
-            .collect::<Vec<_>>();
+            .map(str::parse::<BagCount>)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Rule { key, value }
+        Ok(Rule { key, value })
     }
 }
 
@@ -165,10 +161,10 @@ pub struct BagCount {
     bag: BagInfo,
 }
 
-impl<T: AsRef<str>> From<T> for BagCount {
-    fn from(s: T) -> Self {
-        let s = s.as_ref();
+impl FromStr for BagCount {
+    type Err = ParseRuleError;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         // format:
         // [COUNT] [BAG PROPERTY]
         //
@@ -178,15 +174,22 @@ impl<T: AsRef<str>> From<T> for BagCount {
         // discard rest
 
         if s.contains("no other bags") {
-            return Default::default();
+            return Ok(Default::default());
         }
 
-        let mut iter = s.split(' ');
+        let (count, bag) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseRuleError::InvalidBag(s.to_owned()))?;
 
-        let count = iter.next().unwrap().parse::<usize>().unwrap();
-        let bag = iter.collect();
+        let count = count
+            .parse::<usize>()
+            .map_err(|error| ParseRuleError::InvalidCount {
+                count: count.to_owned(),
+                error,
+            })?;
+        let bag = bag.parse()?;
 
-        BagCount { count, bag }
+        Ok(BagCount { count, bag })
     }
 }
 
@@ -210,25 +213,30 @@ pub struct BagInfo {
     color: String,
 }
 
-impl<A: AsRef<str> + Clone> FromIterator<A> for BagInfo {
-    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let mut iter = iter.into_iter();
+impl BagInfo {
+    pub fn new(adjective: &str, color: &str) -> Self {
+        BagInfo {
+            adjective: adjective.to_owned(),
+            color: color.to_owned(),
+        }
+    }
+}
 
+impl FromStr for BagInfo {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         // first item = adjective
         // second item = color
         // keep both as string
         // discard rest (bag/bags/...)
 
-        let adjective = iter.next().unwrap().as_ref().to_owned();
-        let color = iter.next().unwrap().as_ref().to_owned();
+        let mut iter = s.split(' ').filter(|word| !word.is_empty());
 
-        BagInfo { adjective, color }
-    }
-}
-
-impl<T: AsRef<str>> From<T> for BagInfo {
-    fn from(s: T) -> Self {
-        s.as_ref().split(' ').collect()
+        match (iter.next(), iter.next()) {
+            (Some(adjective), Some(color)) => Ok(BagInfo::new(adjective, color)),
+            _ => Err(ParseRuleError::InvalidBag(s.to_owned())),
+        }
     }
 }
 
@@ -239,3 +247,36 @@ impl fmt::Display for BagInfo {
 }
 
 //##################
+
+pub mod error {
+    use std::num::ParseIntError;
+
+    use thiserror::Error;
+
+    #[derive(Debug, Error, Clone, Eq, PartialEq)]
+    pub enum ParseRuleError {
+        #[error("rule has to end with \".\"")]
+        MissingPeriod(String),
+        #[error("rule has to contain \"contain\"")]
+        MissingContain(String),
+        #[error("bag needs an adjective and a color, found \"{0}\"")]
+        InvalidBag(String),
+        #[error("invalid bag count \"{count}\": {error}")]
+        InvalidCount {
+            count: String,
+            #[source]
+            error: ParseIntError,
+        },
+    }
+
+    impl ParseRuleError {
+        /// Part of the rule that is malformed
+        pub fn fragment(&self) -> &str {
+            match self {
+                ParseRuleError::MissingPeriod(rule) | ParseRuleError::MissingContain(rule) => rule,
+                ParseRuleError::InvalidBag(bag) => bag,
+                ParseRuleError::InvalidCount { count, .. } => count,
+            }
+        }
+    }
+}
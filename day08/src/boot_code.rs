@@ -104,7 +104,7 @@ impl fmt::Display for Instruction {
 
 impl AsRef<Instruction> for Instruction {
     fn as_ref(&self) -> &Instruction {
-        &self
+        self
     }
 }
 
@@ -133,7 +133,8 @@ impl Machine {
             }
             Instruction::Jmp(sign, value) => match sign {
                 Sign::Plus => self.pc += value,
-                Sign::Minus => self.pc -= value,
+                // jumping before the start leaves the program like jumping past the end
+                Sign::Minus => self.pc = self.pc.checked_sub(*value).unwrap_or(usize::MAX),
             },
         }
     }
@@ -165,6 +166,7 @@ pub mod error {
     use thiserror::Error;
 
     #[derive(Error, Debug, Eq, PartialEq, Clone)]
+    #[allow(clippy::enum_variant_names)]
     pub enum ParseInstructionError {
         #[error("can not use input: {0}")]
         MalformedInputError(String),
@@ -189,3 +191,32 @@ pub mod error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // "-0" parses as "+0", so negative values start at 1
+    fn sign_value() -> impl Strategy<Value = (Sign, usize)> {
+        prop_oneof![
+            (0..=i32::MAX as usize).prop_map(|value| (Sign::Plus, value)),
+            (1..=i32::MAX as usize).prop_map(|value| (Sign::Minus, value)),
+        ]
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            sign_value().prop_map(|(sign, value)| Instruction::Nop(sign, value)),
+            any::<i32>().prop_map(|value| Instruction::Acc(value as isize)),
+            sign_value().prop_map(|(sign, value)| Instruction::Jmp(sign, value)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trip(instruction in instruction()) {
+            prop_assert_eq!(instruction.to_string().parse::<Instruction>().unwrap(), instruction);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+pub mod boot_code;
+
+use std::borrow::Cow;
+
+use common::diagnostic::{parse_lines, Diagnostic};
+
+use boot_code::{Instruction, Machine, TermReason};
+
+pub use error::BootError;
+
+/// The boot code
+pub type Input = Vec<Instruction>;
+
+pub type Error = Diagnostic;
+
+/// Parse one instruction per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    parse_lines(input, str::parse::<Instruction>)
+}
+
+/// Value of the accumulator right before an instruction would run a second time
+pub fn part1(input: &Input) -> Result<isize, BootError> {
+    let mut machine = Machine::default();
+    match machine.run_till_term(input) {
+        TermReason::Loop(acc) => Ok(acc),
+        TermReason::End(acc) => Err(BootError::NoLoop(acc)),
+    }
+}
+
+/// Value of the accumulator after the fixed boot code terminated
+pub fn part2(input: &Input) -> Result<isize, BootError> {
+    let fixed_instructions = fix_looped_instructions_bruteforce(input)?;
+    let mut machine = Machine::default();
+    match machine.run_till_term(&fixed_instructions) {
+        TermReason::End(acc) => Ok(acc),
+        TermReason::Loop(acc) => Err(BootError::StillLooping(acc)),
+    }
+}
+
+pub struct Day08;
+
+impl common::Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Input;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input)?)
+    }
+}
+
+fn get_cow_instructions(instructions: &[Instruction]) -> Vec<Cow<'_, Instruction>> {
+    instructions.iter().map(Cow::Borrowed).collect()
+}
+
+// just brute force the solution
+
+fn fix_looped_instructions_bruteforce(
+    instructions: &[Instruction],
+) -> Result<Vec<Cow<'_, Instruction>>, BootError> {
+    let mut mod_instruct = get_cow_instructions(instructions);
+
+    // Get the index from all nop and jump instructions
+    // change them to the other while keeping the values and try if it runs till end
+    // if it runs the change is a solution
+    let possible_positions = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, ins)| match ins {
+            Instruction::Nop(_, _) => try_change(&mod_instruct, idx),
+            Instruction::Jmp(_, _) => try_change(&mod_instruct, idx),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // the task expects exactly one fix
+    let pos = match possible_positions.as_slice() {
+        [pos] => *pos,
+        [] => return Err(BootError::NoFix),
+        positions => return Err(BootError::SeveralFixes(positions.to_vec())),
+    };
+
+    // apply change, try_change only finds nop and jmp
+    mod_instruct[pos] = Cow::Owned(match instructions[pos] {
+        Instruction::Jmp(sign, value) => Instruction::Nop(sign, value),
+        Instruction::Nop(sign, value) => Instruction::Jmp(sign, value),
+        acc => acc,
+    });
+
+    Ok(mod_instruct)
+}
+
+fn try_change(instructions: &[Cow<'_, Instruction>], idx: usize) -> Option<usize> {
+    let mut instructions = Cow::from(instructions);
+
+    // apply change
+    instructions.to_mut()[idx] = Cow::Owned(match &instructions[idx].as_ref() {
+        Instruction::Jmp(sign, value) => Instruction::Nop(*sign, *value),
+        Instruction::Nop(sign, value) => Instruction::Jmp(*sign, *value),
+        _ => return None,
+    });
+
+    // try
+    match Machine::default().run_till_term(&instructions) {
+        TermReason::Loop(_) => None,
+        TermReason::End(_) => Some(idx),
+    }
+}
+
+pub mod error {
+    use thiserror::Error;
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum BootError {
+        #[error("boot code terminates without a loop, acc {0}")]
+        NoLoop(isize),
+
+        #[error("fixed boot code still loops, acc {0}")]
+        StillLooping(isize),
+
+        #[error("no single changed nop or jmp lets the boot code terminate")]
+        NoFix,
+
+        #[error("changing any of the instructions {0:?} lets the boot code terminate")]
+        SeveralFixes(Vec<usize>),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day08,
+        example_1: common::example_input!("1") => { part1: 5, part2: 8 },
+    }
+
+    #[test]
+    fn unsolvable() {
+        let terminating = parse("nop +0\nacc +1").unwrap();
+        assert_eq!(part1(&terminating), Err(BootError::NoLoop(1)));
+
+        let unfixable = parse("acc +0\njmp -1\njmp -2").unwrap();
+        assert_eq!(part2(&unfixable), Err(BootError::NoFix));
+    }
+}
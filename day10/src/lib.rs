@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use common::diagnostic::{parse_lines, Diagnostic};
+
+pub use error::JoltageError;
+
+/// Joltages of the outlet, all adapters and the device, sorted
+pub type Input = Vec<usize>;
+
+pub type Error = Diagnostic;
+
+/// Parse one adapter joltage per line and add outlet and device
+pub fn parse(input: &str) -> Result<Input, Error> {
+    let mut joltages = parse_input(input)?;
+    joltages.push(0); // Voltage of outlet
+    joltages.sort_unstable();
+    joltages.push(joltages.last().unwrap() + 3); // Voltage of device
+
+    Ok(joltages)
+}
+
+/// Number of 1-jolt differences multiplied by number of 3-jolt differences
+pub fn part1(input: &Input) -> Result<usize, JoltageError> {
+    get_product_differences(input)
+}
+
+/// Number of distinct adapter arrangements
+pub fn part2(input: &Input) -> usize {
+    find_chains(input)
+}
+
+pub struct Day10;
+
+impl common::Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+fn parse_input(content: &str) -> Result<Vec<usize>, Error> {
+    parse_lines(content, str::parse)
+}
+
+fn get_product_differences(input: &[usize]) -> Result<usize, JoltageError> {
+    let diff = get_differences(input)?;
+    Ok(diff[0] * diff[2])
+}
+
+fn get_differences(input: &[usize]) -> Result<[usize; 3], JoltageError> {
+    // sort list
+    // get differences of each element with the previous one (skip first element)
+    let input = input.iter().copied().sorted().collect::<Vec<_>>();
+
+    let mut count = [0; 3];
+
+    for idx in (0..input.len()).skip(1) {
+        let (from, to) = (input[idx - 1], input[idx]);
+        // adapters take 1 to 3 jolts less than they put out
+        match to - from {
+            diff @ 1..=3 => count[diff - 1] += 1,
+            _ => return Err(JoltageError::InvalidGap { from, to }),
+        }
+    }
+
+    Ok(count)
+}
+
+fn find_chains(input: &[usize]) -> usize {
+    // Idea:
+    // go through input back to front -> WORKING_INPUT
+    // target (value on path end) will be initialized with path value 1
+    // every other with path value 0
+    // iterate through WORKING_INPUT
+    // for every value of it (CURR_VALUE) search the input for values that could reach it -> PREV_VALUES
+    // add to the path value of PREV_VALUES the path value of CURR_VALUE
+    // this way, by the time the old PREV_VALUE is CURR_VALUE, all larger values will have been CURR_VALUE and
+    // therefore every path from the new CURR_VALUE to them will have been looked at
+    // after going over whole input the initial node 0 should have a count of every possible path in its path value
+
+    let mut storage = Storage::new();
+    // sort it largest to smallest
+    let input = input.iter().copied().sorted().rev().collect::<Vec<_>>();
+
+    // end of all paths
+    let target = *input.first().unwrap();
+    // beginning of all paths
+    let first = *input.last().unwrap();
+
+    storage.set(target, 1);
+
+    for value in input.iter() {
+        input
+            .iter()
+            // filter for every node that could reach value
+            .filter(|next| {
+                let diff = value.wrapping_sub(**next);
+                0 < diff && diff <= 3
+            })
+            // add path value of value to their path value
+            .for_each(|v| {
+                storage.add(*v, storage.get(*value));
+            });
+    }
+
+    // path value at begin of all paths -> count of all paths
+    storage.get(first)
+}
+
+/// Struct to abstract HashMap to make access ez
+/// mostly just how to add to a field and initialize it if it is empty
+struct Storage {
+    intern: HashMap<usize, usize>,
+}
+impl Storage {
+    fn new() -> Self {
+        Self {
+            intern: HashMap::new(),
+        }
+    }
+
+    fn get(&self, key: usize) -> usize {
+        self.intern.get(&key).copied().unwrap_or_default()
+    }
+
+    fn set(&mut self, key: usize, value: usize) {
+        self.intern.insert(key, value);
+    }
+
+    fn add(&mut self, key: usize, to_add: usize) {
+        let init = self.get(key);
+        self.set(key, init + to_add);
+    }
+}
+
+pub mod error {
+    use thiserror::Error;
+
+    #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    pub enum JoltageError {
+        #[error("can not chain {from} to {to} jolts, the difference has to be 1 to 3")]
+        InvalidGap { from: usize, to: usize },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day10,
+        example_1: common::example_input!("1") => { part1: 35, part2: 8 },
+        example_2: common::example_input!("2") => { part1: 220, part2: 19208 },
+    }
+
+    #[test]
+    fn invalid_gaps() {
+        let gap = parse("5").unwrap();
+        assert_eq!(
+            part1(&gap),
+            Err(JoltageError::InvalidGap { from: 0, to: 5 })
+        );
+
+        let duplicate = parse("1\n2\n2").unwrap();
+        assert_eq!(
+            part1(&duplicate),
+            Err(JoltageError::InvalidGap { from: 2, to: 2 })
+        );
+        assert!(part1(&parse("0").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_find_chains() {
+        let input = vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4, 22, 0];
+        assert_eq!(find_chains(&input), 8)
+    }
+}
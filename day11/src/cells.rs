@@ -1,17 +1,14 @@
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Write;
 use std::str::FromStr;
 
-use common::Point;
-
-use error::ParseCellStateError;
+use common::neighbors::{Bounds, Direction, Neighborhood};
+use common::{Grid, Point};
 
 #[derive(Eq, PartialEq, Clone, Default, Debug, Hash)]
 pub struct CellMap {
-    width: usize,
-    height: usize,
-    content: Vec<Vec<CellState>>,
+    content: Grid<CellState>,
 }
 
 impl CellMap {
@@ -29,24 +26,17 @@ impl CellMap {
     pub fn step_part_1(&self) -> CellMap {
         let mut result = self.clone();
 
-        for y in 0..(self.height as isize) {
-            for x in 0..(self.width as isize) {
+        for y in 0..(self.content.height() as isize) {
+            for x in 0..(self.content.width() as isize) {
                 if matches!(self.get_at(x, y), CellState::Floor | CellState::Invalid) {
                     continue;
                 }
 
-                let mut counter = 0;
-                for d_y in (y - 1)..=(y + 1) {
-                    for d_x in (x - 1)..=(x + 1) {
-                        if d_x == x && d_y == y {
-                            continue;
-                        }
-
-                        if self.get_at(d_x, d_y) == CellState::OccupiedSeat {
-                            counter += 1;
-                        }
-                    }
-                }
+                let counter = self
+                    .bounds()
+                    .neighbors(Point { x, y }, Neighborhood::Moore(1))
+                    .filter(|pos| self.get_at(pos.x, pos.y) == CellState::OccupiedSeat)
+                    .count();
 
                 let y_checked = y as usize;
                 let x_checked = x as usize;
@@ -84,8 +74,8 @@ impl CellMap {
     pub fn step_part_2(&self) -> CellMap {
         let mut result = self.clone();
 
-        for y in 0..self.height {
-            for x in 0..self.width {
+        for y in 0..self.content.height() {
+            for x in 0..self.content.width() {
                 if matches!(
                     self.get_at(x as isize, y as isize),
                     CellState::Floor | CellState::Invalid
@@ -93,18 +83,10 @@ impl CellMap {
                     continue;
                 }
 
-                let mut counter = 0;
-                for d_y in -1..=1 {
-                    for d_x in -1..=1 {
-                        if d_x == 0 && d_y == 0 {
-                            continue;
-                        }
-
-                        if self.look_at(x, y, d_x, d_y) == CellState::OccupiedSeat {
-                            counter += 1;
-                        }
-                    }
-                }
+                let counter = Direction::ALL
+                    .iter()
+                    .filter(|dir| self.look_at(x, y, **dir) == CellState::OccupiedSeat)
+                    .count();
 
                 match (self.get_at(x as isize, y as isize), counter) {
                     (CellState::EmptySeat, 0) => result.set_at(x, y, CellState::OccupiedSeat),
@@ -123,29 +105,26 @@ impl CellMap {
     pub fn count_in_state(&self, state: CellState) -> usize {
         self.content
             .iter()
-            .map(|l| l.iter().filter(|item| **item == state))
-            .flatten()
+            .filter(|(_, item)| **item == state)
             .count()
     }
 
-    /// Looks in the direction defined by (dir_x, dir_y) starting from (pos_x, pos_y)
-    /// Floor cells get skipped until the first hit of an Invalid cell, EmptySeat or OccupiedSeat
-    /// On Invalid Cell return Floor
+    /// Looks in direction starting from (pos_x, pos_y)
+    /// Floor cells get skipped until the first hit of an EmptySeat or OccupiedSeat
+    /// On leaving the map return Floor
     /// On EmptySeat or OccupiedSeat return its value
-    pub fn look_at(&self, pos_x: usize, pos_y: usize, dir_x: isize, dir_y: isize) -> CellState {
-        let delta: Point<_> = (dir_x, dir_y).into();
-        let mut current_pos: Point<_> = (pos_x as isize, pos_y as isize).into();
+    pub fn look_at(&self, pos_x: usize, pos_y: usize, direction: Direction) -> CellState {
+        let start: Point<_> = (pos_x as isize, pos_y as isize).into();
+
+        self.bounds()
+            .ray(start, direction)
+            .map(|pos| self.get_at(pos.x, pos.y))
+            .find(|state| *state != CellState::Floor)
+            .unwrap_or(CellState::Floor)
+    }
 
-        loop {
-            current_pos += delta;
-            let res = self.get_at(current_pos.x, current_pos.y);
-            match res {
-                CellState::Floor => continue,
-                CellState::Invalid => return CellState::Floor,
-                x if matches!(x, CellState::EmptySeat | CellState::OccupiedSeat) => return x,
-                _ => unreachable!(),
-            }
-        }
+    pub fn bounds(&self) -> Bounds {
+        self.content.bounds()
     }
 
     /// Check if x and y are valid
@@ -153,26 +132,15 @@ impl CellMap {
     /// if valid:
     /// return CellState at position (x,y)
     pub fn get_at(&self, x: isize, y: isize) -> CellState {
-        if x < 0 || y < 0 {
-            return CellState::Invalid;
-        }
-
-        let x_neg_checked = x as usize;
-        let y_neg_checked = y as usize;
-
-        if x_neg_checked >= self.width || y_neg_checked >= self.height {
-            return CellState::Invalid;
-        }
-
-        let x_checked = x_neg_checked;
-        let y_checked = y_neg_checked;
-
-        self.content[y_checked][x_checked]
+        self.content
+            .get_checked(Point { x, y })
+            .copied()
+            .unwrap_or(CellState::Invalid)
     }
 
     /// Sets CellState at position (x,y) to state
     pub fn set_at(&mut self, x: usize, y: usize, state: CellState) {
-        self.content[y][x] = state
+        self.content[(x, y)] = state
     }
 }
 
@@ -183,40 +151,27 @@ impl FromStr for CellMap {
     type Err = error::ParseCellMapError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let content = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(char::try_into)
-                    .collect::<Result<Vec<CellState>, ParseCellStateError>>()
-            })
-            .collect::<Result<Vec<Vec<CellState>>, ParseCellStateError>>()?;
-
-        let height = content.len();
-        let width = content[0].len();
-
         Ok(Self {
-            width,
-            height,
-            content,
+            content: s.parse()?,
         })
     }
 }
 
-#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+impl fmt::Display for CellMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.content)
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default, Hash)]
 pub enum CellState {
+    #[default]
     Floor,
     EmptySeat,
     OccupiedSeat,
     Invalid,
 }
 
-impl Default for CellState {
-    fn default() -> Self {
-        CellState::Floor
-    }
-}
-
 impl fmt::Display for CellState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -250,9 +205,5 @@ pub mod error {
         UnknownChar(char),
     }
 
-    #[derive(Debug, Error)]
-    pub enum ParseCellMapError {
-        #[error("unknown char: {0}")]
-        CellStateError(#[from] ParseCellStateError),
-    }
+    pub type ParseCellMapError = common::grid::error::ParseGridError<ParseCellStateError>;
 }
@@ -0,0 +1,76 @@
+pub mod cells;
+
+use common::diagnostic::Diagnostic;
+
+use cells::{CellMap, CellState};
+
+/// The initial seat layout
+pub type Input = CellMap;
+
+pub type Error = Diagnostic;
+
+/// Parse the seat layout, one row per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    input
+        .parse::<CellMap>()
+        .map_err(|err| err.into_diagnostic(input))
+}
+
+/// Occupied seats once the layout is stable with the rules of part 1
+pub fn part1(input: &Input) -> usize {
+    let result = run_til_no_change(input.clone(), CellMap::step_part_1);
+    result.count_in_state(CellState::OccupiedSeat)
+}
+
+/// Occupied seats once the layout is stable with the rules of part 2
+pub fn part2(input: &Input) -> usize {
+    let result = run_til_no_change(input.clone(), CellMap::step_part_2);
+    result.count_in_state(CellState::OccupiedSeat)
+}
+
+pub struct Day11;
+
+impl common::Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+fn run_til_no_change(state: CellMap, step: impl Fn(&CellMap) -> CellMap) -> CellMap {
+    let mut prev_state;
+    let mut next_state = state;
+    loop {
+        prev_state = next_state;
+        next_state = step(&prev_state);
+
+        if prev_state == next_state {
+            break;
+        }
+    }
+
+    prev_state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day11,
+        example_1: common::example_input!("1") => { part1: 37, part2: 26 },
+    }
+}
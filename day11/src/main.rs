@@ -1,44 +1,22 @@
-mod cells;
-
-use std::fs;
-
-use cells::{CellMap, CellState};
+use common::report::Part;
+use day11::Day11;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.source_for::<Day11>()?)?;
 
-    let initial_state = content.parse::<CellMap>()?;
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day11>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // Part 1
-    let part_1_res = run_til_no_change(initial_state.clone(), CellMap::step_part_1);
-    println!(
-        "Part 1 Occupied seats: {}",
-        part_1_res.count_in_state(CellState::OccupiedSeat)
-    );
+    let report = common::solution::solve::<Day11>(&content)?
+        .with_label(Part::One, "Part 1 Occupied seats")
+        .with_label(Part::Two, "Part 2 Occupied seats");
 
-    // Part 2
-    let part_2_res = run_til_no_change(initial_state, CellMap::step_part_2);
-    println!(
-        "Part 2 Occupied seats: {}",
-        part_2_res.count_in_state(CellState::OccupiedSeat)
-    );
+    options.format.print(&[report])?;
 
     Ok(())
 }
-
-fn run_til_no_change(state: CellMap, step: impl Fn(&CellMap) -> CellMap) -> CellMap {
-    let mut prev_state;
-    let mut next_state = state;
-    loop {
-        prev_state = next_state;
-        next_state = step(&prev_state);
-
-        if prev_state == next_state {
-            break;
-        }
-    }
-
-    prev_state
-}
@@ -0,0 +1,87 @@
+pub mod ship;
+
+use common::diagnostic::Diagnostic;
+
+use ship::{MovementDirection, Part1Position, Part2Position};
+
+/// The navigation instructions
+pub type Input = Vec<MovementDirection>;
+
+pub type Error = Diagnostic;
+
+/// Parse one navigation instruction per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    get_movement_directions(input)
+}
+
+/// Manhattan distance to the start after moving the ship directly
+pub fn part1(input: &Input) -> usize {
+    let mut position = Part1Position::default();
+    position += input.iter();
+    position.manhatten_distance()
+}
+
+/// Manhattan distance to the start after moving the ship by its waypoint
+pub fn part2(input: &Input) -> usize {
+    let mut position = Part2Position::default();
+    position += input.iter();
+    position.manhatten_distance()
+}
+
+pub struct Day12;
+
+impl common::Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+fn get_movement_directions(input: &str) -> Result<Vec<MovementDirection>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse::<MovementDirection>().map_err(|err| {
+                let fragment = err.fragment(line);
+                Diagnostic::at_line(input, index + 1, err.to_string())
+                    .narrow_to(fragment)
+                    .with_error(err)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day12,
+        example_1: common::example_input!("1") => { part1: 25, part2: 286 },
+    }
+
+    #[test]
+    fn invalid() {
+        let err = parse("F10\nR45\nN3").unwrap_err();
+        assert_eq!(err.span(), common::diagnostic::Span::new(2, 2, 2));
+        assert_eq!(err.message(), "turn of 45 degrees is not a multiple of 90");
+
+        let err = parse("F10\nX3").unwrap_err();
+        assert_eq!(err.span(), common::diagnostic::Span::new(2, 1, 1));
+        assert!(parse("F10\n\nN3").is_err());
+    }
+}
@@ -1,36 +1,22 @@
-mod ship;
-
-use std::fs;
-
-use ship::{error::ParseMovementDirectionError, MovementDirection, Part1Position, Part2Position};
+use common::report::Part;
+use day12::Day12;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let input = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.source_for::<Day12>()?)?;
 
-    let directions = get_movement_directions(&input)?;
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day12>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // Part 1
-    let mut part_1_pos = Part1Position::default();
-    part_1_pos += directions.iter();
-    println!(
-        "Part 1 distance from start to end: {}",
-        part_1_pos.manhatten_distance()
-    );
+    let report = common::solution::solve::<Day12>(&content)?
+        .with_label(Part::One, "Part 1 distance from start to end")
+        .with_label(Part::Two, "Part 2 distance from start to end");
 
-    // Part 2
-    let mut part_2_pos = Part2Position::default();
-    part_2_pos += directions.iter();
-    println!(
-        "Part 2 distance from start to end: {}",
-        part_2_pos.manhatten_distance()
-    );
-    Ok(())
-}
+    options.format.print(&[report])?;
 
-fn get_movement_directions(
-    input: &str,
-) -> Result<Vec<MovementDirection>, ParseMovementDirectionError> {
-    input.lines().map(str::parse).collect()
+    Ok(())
 }
@@ -20,7 +20,7 @@ impl HorDir {
             HorDir::West => VerDir::South,
         }
     }
-    
+
     pub fn turn_right(self) -> VerDir {
         match self {
             HorDir::East => VerDir::South,
@@ -42,7 +42,7 @@ impl VerDir {
             VerDir::South => HorDir::East,
         }
     }
-    
+
     pub fn turn_right(self) -> HorDir {
         match self {
             VerDir::North => HorDir::East,
@@ -70,7 +70,7 @@ impl PositionPart<HorDir> {
             value: self.value,
         }
     }
-    
+
     pub fn turn_right(self) -> PositionPart<VerDir> {
         PositionPart {
             direction: self.direction.turn_right(),
@@ -115,12 +115,21 @@ impl FromStr for MovementDirection {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use error::ParseMovementDirectionError;
 
-        let kind = s[0..1]
-            .chars()
+        let mut chars = s.chars();
+        let kind = chars
             .next()
             .ok_or(ParseMovementDirectionError::GeneralInputError)?
             .try_into()?;
-        let value = s[1..].parse()?;
+        let value = chars.as_str().parse()?;
+
+        // the ship only turns in right angles
+        if matches!(
+            kind,
+            MovementDirectionKind::Left | MovementDirectionKind::Right
+        ) && value % 90 != 0
+        {
+            return Err(ParseMovementDirectionError::NotRightAngle(value));
+        }
 
         Ok(Self { kind, value })
     }
@@ -159,6 +168,7 @@ pub mod error {
     use thiserror::Error;
 
     #[derive(Debug, Error)]
+    #[allow(clippy::enum_variant_names)]
     pub enum ParseMovementDirectionError {
         #[error("general error with formatting of input")]
         GeneralInputError,
@@ -166,6 +176,23 @@ pub mod error {
         ParseMovementDirectionKindError(#[from] ParseMovementDirectionKindError),
         #[error("error parsing integer value")]
         ParseIntegerError(#[from] ParseIntError),
+        #[error("turn of {0} degrees is not a multiple of 90")]
+        NotRightAngle(usize),
+    }
+
+    impl ParseMovementDirectionError {
+        /// Part of the line the error is about
+        pub fn fragment<'a>(&self, line: &'a str) -> &'a str {
+            let value_start = line.chars().next().map_or(0, char::len_utf8);
+            match self {
+                ParseMovementDirectionError::GeneralInputError => line,
+                ParseMovementDirectionError::ParseMovementDirectionKindError(_) => {
+                    &line[..value_start]
+                }
+                ParseMovementDirectionError::ParseIntegerError(_)
+                | ParseMovementDirectionError::NotRightAngle(_) => &line[value_start..],
+            }
+        }
     }
 
     #[derive(Debug, Error, Copy, Clone)]
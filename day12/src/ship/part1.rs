@@ -77,7 +77,7 @@ impl Part1Position {
     }
 
     fn turn_left(&mut self, value: usize) {
-        assert_eq!(value % 90, 0);
+        debug_assert_eq!(value % 90, 0, "turns are checked while parsing");
 
         let mut temp = value;
         while temp > 0 {
@@ -93,7 +93,7 @@ impl Part1Position {
     }
 
     fn turn_right(&mut self, value: usize) {
-        assert_eq!(value % 90, 0);
+        debug_assert_eq!(value % 90, 0, "turns are checked while parsing");
 
         let mut temp = value;
         while temp > 0 {
@@ -0,0 +1,129 @@
+pub mod notes;
+
+use std::fmt;
+
+use anyhow::Context;
+
+use common::diagnostic::Diagnostic;
+use common::report::Answer;
+
+use notes::Notes;
+
+/// Earliest departure and the bus schedule
+pub type Input = Notes;
+
+pub type Error = Diagnostic;
+
+/// Parse the timestamp on the first and the schedule on the second line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    input.parse()
+}
+
+/// Earliest bus to take to the airport, None without any bus in service
+pub fn part1(input: &Input) -> Option<Part1Result> {
+    let busses = input.bus_ids().collect::<Vec<_>>();
+    part_1(input.timestamp, &busses)
+}
+
+/// Earliest timestamp such that all busses depart at offsets matching their position
+pub fn part2(input: &Input) -> usize {
+    let busses = input
+        .busses
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, bus)| bus.map(|id| (idx, id)))
+        .collect::<Vec<_>>();
+    part_2(&busses)
+}
+
+pub struct Day13;
+
+impl common::Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = Input;
+    type Part1 = Part1Result;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        part1(input).context("no bus in service")
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+// Part 1
+
+/// Earliest bus to take
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Part1Result {
+    pub bus_id: usize,
+    pub earliest_time: usize,
+    pub wait_time: usize,
+}
+
+/// The answer is the bus id multiplied by the wait time
+impl fmt::Display for Part1Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.bus_id * self.wait_time)
+    }
+}
+
+impl Answer for Part1Result {
+    fn extra(&self) -> Vec<(String, String)> {
+        vec![
+            ("bus_id".to_owned(), self.bus_id.to_string()),
+            ("earliest_time".to_owned(), self.earliest_time.to_string()),
+            ("wait_time".to_owned(), self.wait_time.to_string()),
+        ]
+    }
+}
+
+fn part_1(timestamp: usize, busses: &[usize]) -> Option<Part1Result> {
+    let (id, wait_time) = busses
+        .iter()
+        .map(|bus| (bus, bus - (timestamp % bus)))
+        .min_by(|x, y| x.1.cmp(&y.1))?;
+    let earliest_time = timestamp + wait_time;
+
+    Some(Part1Result {
+        bus_id: *id,
+        earliest_time,
+        wait_time,
+    })
+}
+
+// Part 2
+// https://www.reddit.com/r/rust/comments/kc5phc/advent_of_code_2020_day_13/gfob95b/
+
+fn inv_mod(x: isize, p: isize) -> isize {
+    // p must be prime
+    (0..p - 2).fold(1, |o, _| (o * x) % p)
+}
+
+fn part_2(busses: &[(usize, usize)]) -> usize {
+    let prod = busses.iter().map(|(_, val)| val).product::<usize>() as isize;
+
+    busses
+        .iter()
+        .map(|&(pos, val)| (pos as isize, val as isize))
+        .map(|(pos, val)| -pos * (prod / val) * inv_mod(prod / val, val))
+        .sum::<isize>()
+        .rem_euclid(prod) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day13,
+        example_1: common::example_input!("1") => { part1: 295, part2: 1068781 },
+    }
+}
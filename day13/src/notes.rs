@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use common::diagnostic::{Diagnostic, Span};
+
+/// Earliest departure and the bus schedule
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Notes {
+    pub timestamp: usize,
+    /// Bus ids by position in the schedule, None for "x"
+    pub busses: Vec<Option<usize>>,
+}
+
+impl Notes {
+    /// Bus ids that are in service
+    pub fn bus_ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.busses.iter().filter_map(|bus| *bus)
+    }
+}
+
+// format:
+// [TIMESTAMP]
+// [BUS ID or x]{,[BUS ID or x]}
+impl FromStr for Notes {
+    type Err = Diagnostic;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let timestamp_line = lines
+            .next()
+            .ok_or_else(|| Diagnostic::new(s, Span::new(1, 1, 0), "missing earliest timestamp"))?;
+        let timestamp = timestamp_line.parse().map_err(|err| {
+            Diagnostic::at_fragment(s, timestamp_line, format!("invalid timestamp: {}", err))
+                .with_error(err)
+        })?;
+
+        let schedule = lines
+            .next()
+            .ok_or_else(|| Diagnostic::new(s, Span::new(2, 1, 0), "missing bus schedule"))?;
+        let busses = schedule
+            .split(',')
+            .map(|entry| match entry {
+                "x" => Ok(None),
+                _ => match entry.parse::<usize>() {
+                    Ok(0) => Err(Diagnostic::at_fragment(s, entry, "bus id must not be 0")),
+                    Ok(id) => Ok(Some(id)),
+                    Err(err) => Err(Diagnostic::at_fragment(
+                        s,
+                        entry,
+                        format!("invalid bus id \"{}\": {}", entry, err),
+                    )
+                    .with_label("expected a number or \"x\"")
+                    .with_error(err)),
+                },
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Notes { timestamp, busses })
+    }
+}
@@ -0,0 +1,62 @@
+pub mod program;
+
+use common::diagnostic::{parse_lines, Diagnostic};
+
+use program::{apply_list, Instruction, Memory};
+
+/// The initialization program
+pub type Input = Vec<Instruction>;
+
+pub type Error = Diagnostic;
+
+/// Parse one instruction per line
+pub fn parse(input: &str) -> Result<Input, Error> {
+    parse_lines(input, str::parse::<Instruction>)
+}
+
+/// Sum of memory after running the program with the decoder chip v1
+pub fn part1(input: &Input) -> usize {
+    let mut memory = Memory::default();
+    apply_list(&mut memory, Memory::apply_v1, input);
+    memory.sum()
+}
+
+/// Sum of memory after running the program with the decoder chip v2
+pub fn part2(input: &Input) -> usize {
+    let mut memory = Memory::default();
+    apply_list(&mut memory, Memory::apply_v2, input);
+    memory.sum()
+}
+
+pub struct Day14;
+
+impl common::Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day14,
+        example_1: common::example_input!("1") => { part1: 165 },
+        example_2: common::example_input!("2") => { part2: 208 },
+    }
+}
@@ -1,24 +1,22 @@
-mod program;
-
-use crate::program::{apply_list, parse_instruction_list, Memory};
-use std::fs;
+use common::report::Part;
+use day14::Day14;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.source_for::<Day14>()?)?;
 
-    let instructions = parse_instruction_list(&content)?;
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day14>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // part 1
-    let mut memory = Memory::default();
-    apply_list(&mut memory, Memory::apply_v1, &instructions);
-    println!("V1: Sum of memory after completion: {}", memory.sum());
+    let report = common::solution::solve::<Day14>(&content)?
+        .with_label(Part::One, "V1: Sum of memory after completion")
+        .with_label(Part::Two, "V2: Sum of memory after completion");
 
-    // part 2
-    let mut memory = Memory::default();
-    apply_list(&mut memory, Memory::apply_v2, &instructions);
-    println!("V2: Sum of memory after completion: {}", memory.sum());
+    options.format.print(&[report])?;
 
     Ok(())
 }
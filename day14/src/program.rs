@@ -1,6 +1,6 @@
 mod instruction;
 
-pub use instruction::{parse_instruction_list, Instruction, Mask, Write};
+pub use instruction::{error, parse_instruction_list, Instruction, Mask, Write};
 
 use std::collections::HashMap;
 
@@ -1,4 +1,5 @@
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 pub fn parse_instruction_list(
@@ -31,6 +32,15 @@ impl FromStr for Instruction {
     }
 }
 
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Mask { mask } => write!(f, "{}", mask),
+            Instruction::Write { write } => write!(f, "{}", write),
+        }
+    }
+}
+
 // Parsing of Write Instruction
 #[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Hash)]
 pub struct Write {
@@ -70,6 +80,13 @@ impl FromStr for Write {
     }
 }
 
+/// Format: mem[TO] = VALUE
+impl fmt::Display for Write {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mem[{}] = {}", self.to, self.value)
+    }
+}
+
 // Mask Instruction -> Parsing, Storage and Applying of Mask
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Mask {
@@ -108,8 +125,7 @@ impl Mask {
 
         let mut chunk_iter = applied_static.split('X');
 
-        let mut result = Vec::new();
-        result.push(chunk_iter.next().unwrap().to_owned());
+        let mut result = vec![chunk_iter.next().unwrap().to_owned()];
 
         for part in chunk_iter {
             let temp = result;
@@ -167,20 +183,26 @@ impl FromStr for Mask {
     }
 }
 
+/// Format: mask = 36 times 0, 1 or X
+impl fmt::Display for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mask = ")?;
+        for value in self.mask.iter() {
+            write!(f, "{}", char::from(*value))?;
+        }
+        Ok(())
+    }
+}
+
 // MaskValue -> Individual digits of Mask -> Parsing
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Hash)]
 enum MaskValue {
     Zero,
     One,
+    #[default]
     DontCare,
 }
 
-impl Default for MaskValue {
-    fn default() -> Self {
-        MaskValue::DontCare
-    }
-}
-
 impl TryFrom<char> for MaskValue {
     type Error = error::ParseMaskValueError;
 
@@ -194,6 +216,16 @@ impl TryFrom<char> for MaskValue {
     }
 }
 
+impl From<MaskValue> for char {
+    fn from(value: MaskValue) -> Self {
+        match value {
+            MaskValue::Zero => '0',
+            MaskValue::One => '1',
+            MaskValue::DontCare => 'X',
+        }
+    }
+}
+
 // Error handling
 pub mod error {
     use std::num::ParseIntError;
@@ -201,6 +233,7 @@ pub mod error {
     use thiserror::Error;
 
     #[derive(Error, Debug, Clone, Eq, PartialEq)]
+    #[allow(clippy::enum_variant_names)]
     pub enum ParseInstructionError {
         #[error("error parsing mask: {error}")]
         ParseMaskError {
@@ -248,3 +281,44 @@ pub mod error {
         UnknownCharError(char),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn mask() -> impl Strategy<Value = Mask> {
+        let value = prop_oneof![
+            Just(MaskValue::Zero),
+            Just(MaskValue::One),
+            Just(MaskValue::DontCare)
+        ];
+
+        proptest::collection::vec(value, 36).prop_map(|values| {
+            let mut mask = Mask::default();
+            mask.mask.copy_from_slice(&values);
+            mask
+        })
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            mask().prop_map(|mask| Instruction::Mask { mask }),
+            (any::<usize>(), any::<usize>()).prop_map(|(to, value)| Instruction::Write {
+                write: Write { to, value }
+            }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn mask_round_trip(mask in mask()) {
+            prop_assert_eq!(mask.to_string().parse::<Mask>().unwrap(), mask);
+        }
+
+        #[test]
+        fn instruction_round_trip(instruction in instruction()) {
+            prop_assert_eq!(instruction.to_string().parse::<Instruction>().unwrap(), instruction);
+        }
+    }
+}
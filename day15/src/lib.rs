@@ -0,0 +1,59 @@
+pub mod spoken_number;
+
+use anyhow::Context;
+
+use common::diagnostic::{parse_separated, Diagnostic};
+
+use spoken_number::SpokenNumber;
+
+/// The starting numbers
+pub type Input = Vec<usize>;
+
+pub type Error = Diagnostic;
+
+/// Parse the comma separated starting numbers
+pub fn parse(input: &str) -> Result<Input, Error> {
+    parse_separated(input, ',', str::parse::<usize>)
+}
+
+/// The 2020th number spoken
+pub fn part1(input: &Input) -> Option<usize> {
+    SpokenNumber::new(input.clone()).nth(2020 - 1)
+}
+
+/// The 30000000th number spoken
+pub fn part2(input: &Input) -> Option<usize> {
+    SpokenNumber::new(input.clone()).nth(30000000 - 1)
+}
+
+pub struct Day15;
+
+impl common::Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        part1(input).context("sequence ended early")
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        part2(input).context("sequence ended early")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day15,
+        example_1: common::example_input!("1") => { part1: 436 },
+    }
+}
@@ -1,30 +1,22 @@
-mod spoken_number;
-
-use crate::spoken_number::SpokenNumber;
-use std::fs;
+use common::report::Part;
+use day15::Day15;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.source_for::<Day15>()?)?;
 
-    let numbers = content
-        .split(',')
-        .map(str::parse::<usize>)
-        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day15>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // Part 1
-    let result = SpokenNumber::new(numbers.clone()).nth(2020 - 1).unwrap();
-    println!("2020th spoken number with start {:?}: {}", numbers, result);
+    let report = common::solution::solve::<Day15>(&content)?
+        .with_label(Part::One, "2020th spoken number")
+        .with_label(Part::Two, "30000000th spoken number");
 
-    // Part 2
-    let result = SpokenNumber::new(numbers.clone())
-        .nth(30000000 - 1)
-        .unwrap();
-    println!(
-        "30000000th spoken number with start {:?}: {}",
-        numbers, result
-    );
+    options.format.print(&[report])?;
 
     Ok(())
 }
@@ -0,0 +1,50 @@
+pub mod ticket;
+
+use common::diagnostic::Diagnostic;
+
+/// Rules, my ticket and nearby tickets
+pub type Input = ticket::Input;
+
+pub type Error = Diagnostic;
+
+/// Parse the notes
+pub fn parse(input: &str) -> Result<Input, Error> {
+    input.parse::<ticket::Input>().map_err(|err| {
+        let line = err.line();
+        Diagnostic::at_line(input, line, err.to_string()).with_error(err)
+    })
+}
+
+/// Ticket scanning error rate of the nearby tickets
+pub fn part1(input: &Input) -> usize {
+    input.part_1_result()
+}
+
+/// Product of all "departure" fields on my ticket
+pub fn part2(input: &Input) -> usize {
+    let mut input = input.clone();
+    input.cleanup_invalid_tickets();
+    input.part_2_result()
+}
+
+pub struct Day16;
+
+impl common::Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
@@ -1,24 +1,22 @@
-mod ticket;
-
-use std::fs;
-
-use ticket::Input;
+use common::report::Part;
+use day16::Day16;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
+    let content = common::input::load(&options.source_for::<Day16>()?)?;
 
-    let mut input_data = content.parse::<Input>()?;
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day16>(&content, runs)?;
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
 
-    // Part 1
-    let result_1 = input_data.part_1_result();
-    println!("Ticket scanning error rate: {}", result_1);
+    let report = common::solution::solve::<Day16>(&content)?
+        .with_label(Part::One, "Ticket scanning error rate")
+        .with_label(Part::Two, "Product of \"departure\" fields on my ticket");
 
-    // Part 2
-    input_data.cleanup_invalid_tickets();
-    let result_2 = input_data.part_2_result();
-    println!("Product of \"departure\" fields on my ticket: {}", result_2);
+    options.format.print(&[report])?;
 
     Ok(())
 }
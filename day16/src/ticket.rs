@@ -15,8 +15,7 @@ impl Input {
     pub fn get_general_invalid_values_of_other_tickets(&self) -> Vec<usize> {
         self.other_tickets
             .iter()
-            .map(|ticket| self.rules.get_general_invalid_values(ticket))
-            .flatten()
+            .flat_map(|ticket| self.rules.get_general_invalid_values(ticket))
             .collect()
     }
 
@@ -46,7 +45,7 @@ impl Input {
         loop {
             // Get all rule identifier from possible_mappings where there is just one rule in the set
             // -> position is mapped
-            let _ = possible_mappings
+            possible_mappings
                 .iter()
                 .filter_map(|rule_set| {
                     if rule_set.len() == 1 {
@@ -55,19 +54,17 @@ impl Input {
                         None
                     }
                 })
-                .map(|rule| {
+                .for_each(|rule| {
                     checked_rules.insert(rule.to_owned());
-                })
-                .collect::<()>();
+                });
 
             // remove all rules that are final mapped from all other mappings that are bigger than 1
-            let _ = possible_mappings
+            possible_mappings
                 .iter_mut()
                 .filter(|rule_set| rule_set.len() > 1)
-                .map(|rule_set| {
+                .for_each(|rule_set| {
                     rule_set.retain(|elem| !checked_rules.contains(elem));
-                })
-                .collect::<()>();
+                });
 
             // if all is final mapped exit
             if possible_mappings.iter().all(|rule_set| rule_set.len() == 1) {
@@ -112,27 +109,51 @@ impl FromStr for Input {
         use error::ParseInputError;
 
         let mut split_input = s.split("\n\n");
-
-        let rules = split_input
-            .next()
-            .ok_or(ParseInputError::MalformedInput)?
-            .parse()?;
-
-        let my_ticket = split_input
-            .next()
-            .ok_or(ParseInputError::MalformedInput)?
-            .strip_prefix("your ticket:\n")
-            .ok_or(ParseInputError::MalformedInput)?
-            .parse()?;
+        // first line of the current section, 1-based
+        let mut line = 1;
+
+        let rules_section = split_input.next().unwrap_or_default();
+        let rules = rules_section
+            .parse()
+            .map_err(|error: error::ParseTicketRulesError| {
+                ParseInputError::ParseTicketRulesError {
+                    line: line + error.line() - 1,
+                    error,
+                }
+            })?;
+        line += rules_section.lines().count() + 1;
+
+        let my_ticket_section = split_input.next();
+        let my_ticket = my_ticket_section
+            .and_then(|section| section.strip_prefix("your ticket:\n"))
+            .ok_or(ParseInputError::MissingSection {
+                name: "your ticket",
+                line,
+            })?
+            .parse()
+            .map_err(|error| ParseInputError::ParseTicketError {
+                line: line + 1,
+                error,
+            })?;
+        line += my_ticket_section.map_or(0, |section| section.lines().count()) + 1;
 
         let other_tickets = split_input
             .next()
-            .ok_or(ParseInputError::MalformedInput)?
-            .strip_prefix("nearby tickets:\n")
-            .ok_or(ParseInputError::MalformedInput)?
+            .and_then(|section| section.strip_prefix("nearby tickets:\n"))
+            .ok_or(ParseInputError::MissingSection {
+                name: "nearby tickets",
+                line,
+            })?
             .split('\n')
-            .filter(|v| !v.is_empty())
-            .map(str::parse)
+            .enumerate()
+            .filter(|(_, v)| !v.is_empty())
+            .map(|(idx, v)| {
+                v.parse()
+                    .map_err(|error| ParseInputError::ParseTicketError {
+                        line: line + idx + 1,
+                        error,
+                    })
+            })
             .collect::<Result<_, _>>()?;
 
         Ok(Input {
@@ -165,7 +186,7 @@ impl TicketRules {
         ticket.values.iter().all(|value| {
             self.rules
                 .iter()
-                .any(|(_, ranges)| ranges.iter().any(|range| range.contains(&value)))
+                .any(|(_, ranges)| ranges.iter().any(|range| range.contains(value)))
         })
     }
 
@@ -210,13 +231,11 @@ impl FromStr for TicketRules {
 
         let mut rules = HashMap::new();
 
-        for line in s.lines() {
+        for (idx, line) in s.lines().enumerate() {
+            let malformed = ParseTicketRulesError::MalformedInput { line: idx + 1 };
             let mut split = line.split(": ");
             // Key
-            let key = split
-                .next()
-                .ok_or(ParseTicketRulesError::MalformedInput)?
-                .to_owned();
+            let key = split.next().ok_or_else(|| malformed.clone())?.to_owned();
 
             // Ranges
             let ranges = split
@@ -225,15 +244,19 @@ impl FromStr for TicketRules {
                 .map(|range_str| {
                     let range = range_str.split('-').collect::<Vec<_>>();
                     if range.len() != 2 {
-                        Err(ParseTicketRulesError::MalformedInput)
+                        Err(malformed.clone())
                     } else {
                         let values = range
                             .into_iter()
                             .map(str::parse::<usize>)
-                            .collect::<Result<Vec<usize>, ParseIntError>>()?;
+                            .collect::<Result<Vec<usize>, ParseIntError>>()
+                            .map_err(|error| ParseTicketRulesError::ParseIntError {
+                                line: idx + 1,
+                                error,
+                            })?;
 
                         if values.len() != 2 {
-                            Err(ParseTicketRulesError::MalformedInput)
+                            Err(malformed.clone())
                         } else {
                             Ok(values[0]..=values[1])
                         }
@@ -275,6 +298,19 @@ impl FromStr for Ticket {
     }
 }
 
+/// Values separated by commas
+impl fmt::Display for Ticket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, value) in self.values.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        Ok(())
+    }
+}
+
 impl Index<usize> for Ticket {
     type Output = usize;
 
@@ -287,33 +323,57 @@ pub mod error {
     use std::num::ParseIntError;
     use thiserror::Error;
 
+    /// Lines are 1-based and count from the start of the input
     #[derive(Debug, Error, Clone, Eq, PartialEq)]
     pub enum ParseInputError {
-        #[error("malformed input")]
-        MalformedInput,
+        #[error("missing section \"{name}\"")]
+        MissingSection { name: &'static str, line: usize },
         #[error("error parsing ticket rules: {error}")]
         ParseTicketRulesError {
-            #[from]
+            line: usize,
+            #[source]
             error: ParseTicketRulesError,
         },
         #[error("error parsing ticket: {error}")]
         ParseTicketError {
-            #[from]
+            line: usize,
+            #[source]
             error: ParseTicketError,
         },
     }
 
+    impl ParseInputError {
+        pub fn line(&self) -> usize {
+            match self {
+                ParseInputError::MissingSection { line, .. }
+                | ParseInputError::ParseTicketRulesError { line, .. }
+                | ParseInputError::ParseTicketError { line, .. } => *line,
+            }
+        }
+    }
+
+    /// Lines are 1-based and count from the first rule
     #[derive(Debug, Error, Clone, Eq, PartialEq)]
     pub enum ParseTicketRulesError {
-        #[error("malformed input")]
-        MalformedInput,
+        #[error("malformed rule")]
+        MalformedInput { line: usize },
         #[error("error parsing integer: {error}")]
         ParseIntError {
-            #[from]
+            line: usize,
+            #[source]
             error: ParseIntError,
         },
     }
 
+    impl ParseTicketRulesError {
+        pub fn line(&self) -> usize {
+            match self {
+                ParseTicketRulesError::MalformedInput { line }
+                | ParseTicketRulesError::ParseIntError { line, .. } => *line,
+            }
+        }
+    }
+
     #[derive(Debug, Error, Clone, Eq, PartialEq)]
     pub enum ParseTicketError {
         #[error("input not long enough. expected {expected} values. found: {is} values")]
@@ -325,3 +385,20 @@ pub mod error {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn ticket() -> impl Strategy<Value = Ticket> {
+        proptest::collection::vec(any::<usize>(), 20).prop_map(|values| Ticket { values })
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trip(ticket in ticket()) {
+            prop_assert_eq!(ticket.to_string().parse::<Ticket>().unwrap(), ticket);
+        }
+    }
+}
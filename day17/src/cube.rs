@@ -1,12 +1,13 @@
 use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
 use std::hash::Hash;
 use std::ops::Add;
 
-use itertools::Itertools;
+use common::neighbors::Neighborhood;
+use common::{Grid, Point, PointN};
 
-pub use pos_3d::Position3D;
-pub use pos_4d::Position4D;
-pub use pos_5d::Position5D;
+use error::ParseCubeStateError;
 
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct Collection<T>
@@ -38,12 +39,7 @@ impl<T: Position> Collection<T> {
         let mut neighbors_to_check = HashSet::<T>::new();
 
         // Get offsets that are neighbors for easy addition to get neighbor pos
-        let neighbor_offsets = (0..T::DIM)
-            .map(|_| (-1..=1))
-            .multi_cartesian_product()
-            .map(T::from)
-            .filter(|pos| *pos != T::from((0, 0)))
-            .collect::<Vec<_>>();
+        let neighbor_offsets = T::neighbor_offsets();
 
         // For all currently active cells
         //   add all possible neighbor_offsets
@@ -56,7 +52,7 @@ impl<T: Position> Collection<T> {
                 .map(|off| *off + *active)
                 .partition(|pos| self.active_cells.contains(pos));
 
-            neighbors_to_check.extend(inactive_neighbors.into_iter());
+            neighbors_to_check.extend(inactive_neighbors);
             mapping_from_active.insert(*active, active_neighbors.len());
         }
 
@@ -116,211 +112,81 @@ impl<T: Position> Collection<T> {
     }
 }
 
-impl<S: AsRef<str>, T: Position> From<S> for Collection<T> {
-    fn from(s: S) -> Self {
-        let s = s.as_ref();
-
-        // parsing input to Position
-        // take lines -> numerate them => y position
-        //   for each line
-        //   take char and numerate them => x position
-        //   and discard any that are not '#'
-        // collect remaining x and y combinations
-        let active_cells = s
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter_map(|(x, c)| if c == '#' { Some(x) } else { None })
-                    .map(|x| (x as isize, y as isize))
-                    .collect::<Vec<_>>()
+/// The initial slice, placed at zero in all other dimensions
+impl<T: Position> From<&Grid<CubeState>> for Collection<T> {
+    fn from(grid: &Grid<CubeState>) -> Self {
+        let active_cells = grid
+            .iter()
+            .filter(|(_, state)| **state == CubeState::Active)
+            .map(|(pos, _)| {
+                T::from(Point {
+                    x: pos.x as isize,
+                    y: pos.y as isize,
+                })
             })
-            .flatten()
-            .map(T::from)
             .collect::<HashSet<_>>();
 
         Collection { active_cells }
     }
 }
 
-/// Trait that returns the dimension
-pub trait Dim {
-    const DIM: usize;
+// CubeState
+//##################
 
-    fn get_dim() -> usize {
-        Self::DIM
-    }
-}
-
-/// Required Traits for usage as position data in a Collection
-pub trait Position:
-    Add<Output = Self> + Copy + Dim + Eq + From<(isize, isize)> + From<Vec<isize>> + Hash + PartialEq
-{
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CubeState {
+    Active,
+    Inactive,
 }
 
-/// Implementation of 3D Position Data
-pub mod pos_3d {
-    use std::ops::Add;
-
-    use super::Dim;
-    use crate::cube::Position;
-
-    #[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Hash)]
-    pub struct Position3D {
-        pub x: isize,
-        pub y: isize,
-        pub z: isize,
-    }
-
-    impl Dim for Position3D {
-        const DIM: usize = 3;
-    }
-
-    impl Add for Position3D {
-        type Output = Position3D;
+impl TryFrom<char> for CubeState {
+    type Error = ParseCubeStateError;
 
-        fn add(self, rhs: Self) -> Self::Output {
-            Self {
-                x: self.x + rhs.x,
-                y: self.y + rhs.y,
-                z: self.z + rhs.z,
-            }
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(CubeState::Active),
+            '.' => Ok(CubeState::Inactive),
+            c => Err(ParseCubeStateError::UnknownChar(c)),
         }
     }
-
-    impl From<(isize, isize)> for Position3D {
-        fn from((x, y): (isize, isize)) -> Self {
-            Position3D { x, y, z: 0 }
-        }
-    }
-
-    impl From<Vec<isize>> for Position3D {
-        fn from(vec: Vec<isize>) -> Self {
-            assert_eq!(vec.len(), Self::DIM);
-
-            Self {
-                x: vec[0],
-                y: vec[1],
-                z: vec[2],
-            }
-        }
-    }
-
-    impl Position for Position3D {}
 }
 
-/// Implementation of 4D Position Data
-pub mod pos_4d {
-    use std::ops::Add;
-
-    use super::{Dim, Position};
-
-    #[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Hash)]
-    pub struct Position4D {
-        pub x: isize,
-        pub y: isize,
-        pub z: isize,
-        pub w: isize,
-    }
-
-    impl Dim for Position4D {
-        const DIM: usize = 4;
-    }
-
-    impl Add for Position4D {
-        type Output = Position4D;
-
-        fn add(self, rhs: Self) -> Self::Output {
-            Self {
-                x: self.x + rhs.x,
-                y: self.y + rhs.y,
-                z: self.z + rhs.z,
-                w: self.w + rhs.w,
-            }
-        }
-    }
-
-    impl From<(isize, isize)> for Position4D {
-        fn from((x, y): (isize, isize)) -> Self {
-            Self { x, y, z: 0, w: 0 }
-        }
-    }
-
-    impl From<Vec<isize>> for Position4D {
-        fn from(vec: Vec<isize>) -> Self {
-            assert_eq!(vec.len(), Self::DIM);
-
-            Self {
-                x: vec[0],
-                y: vec[1],
-                z: vec[2],
-                w: vec[3],
-            }
+impl fmt::Display for CubeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CubeState::Active => write!(f, "#"),
+            CubeState::Inactive => write!(f, "."),
         }
     }
-
-    impl Position for Position4D {}
 }
 
-/// EXTRA: Implementation of 5D Position Data
-pub mod pos_5d {
-    use std::ops::Add;
+//##################
 
-    use super::{Dim, Position};
+pub type Position3D = PointN<isize, 3>;
+pub type Position4D = PointN<isize, 4>;
+/// EXTRA
+pub type Position5D = PointN<isize, 5>;
 
-    #[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Hash)]
-    pub struct Position5D {
-        pub x: isize,
-        pub y: isize,
-        pub z: isize,
-        pub w: isize,
-        pub v: isize,
-    }
+/// Required Traits for usage as position data in a Collection
+pub trait Position: Add<Output = Self> + Copy + Eq + From<Point<isize>> + Hash {
+    /// Offsets to all neighbors
+    fn neighbor_offsets() -> Vec<Self>;
+}
 
-    impl Dim for Position5D {
-        const DIM: usize = 5;
-    }
-
-    impl Add for Position5D {
-        type Output = Position5D;
-
-        fn add(self, rhs: Self) -> Self::Output {
-            Self {
-                x: self.x + rhs.x,
-                y: self.y + rhs.y,
-                z: self.z + rhs.z,
-                w: self.w + rhs.w,
-                v: self.v + rhs.v,
-            }
-        }
+impl<const N: usize> Position for PointN<isize, N> {
+    fn neighbor_offsets() -> Vec<Self> {
+        Neighborhood::Moore(1).offsets_n().collect()
     }
+}
 
-    impl From<(isize, isize)> for Position5D {
-        fn from((x, y): (isize, isize)) -> Self {
-            Self {
-                x,
-                y,
-                z: 0,
-                w: 0,
-                v: 0,
-            }
-        }
-    }
+pub mod error {
+    use thiserror::Error;
 
-    impl From<Vec<isize>> for Position5D {
-        fn from(vec: Vec<isize>) -> Self {
-            assert_eq!(vec.len(), Self::DIM);
-
-            Self {
-                x: vec[0],
-                y: vec[1],
-                z: vec[2],
-                w: vec[3],
-                v: vec[4],
-            }
-        }
+    #[derive(Debug, Error, Clone, Eq, PartialEq)]
+    pub enum ParseCubeStateError {
+        #[error("unknown char: {0}")]
+        UnknownChar(char),
     }
 
-    impl Position for Position5D {}
+    pub type ParseSliceError = common::grid::error::ParseGridError<ParseCubeStateError>;
 }
@@ -0,0 +1,74 @@
+pub mod cube;
+
+use common::diagnostic::Diagnostic;
+use common::Grid;
+
+use cube::{Collection, CubeState, Position, Position3D, Position4D, Position5D};
+
+/// Cycles to simulate
+const CYCLES: usize = 6;
+
+/// The initial 2D slice, every part extends it to its own dimension
+pub type Input = Grid<CubeState>;
+
+pub type Error = Diagnostic;
+
+/// Parse the initial slice, '#' is active and '.' inactive
+pub fn parse(input: &str) -> Result<Input, Error> {
+    input
+        .parse::<Input>()
+        .map_err(|err| err.into_diagnostic(input))
+}
+
+/// Active cubes in 3D after all cycles
+pub fn part1(input: &Input) -> usize {
+    count_active_after_cycles::<Position3D>(input)
+}
+
+/// Active cubes in 4D after all cycles
+pub fn part2(input: &Input) -> usize {
+    count_active_after_cycles::<Position4D>(input)
+}
+
+pub struct Day17;
+
+impl common::Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<Self::Part2> {
+        Ok(part2(input))
+    }
+}
+
+/// EXTRA: Active cubes in 5D after all cycles
+pub fn extra_5d(input: &Input) -> usize {
+    count_active_after_cycles::<Position5D>(input)
+}
+
+fn count_active_after_cycles<T: Position>(input: &Input) -> usize {
+    Collection::<T>::from(input)
+        .multi_step(CYCLES)
+        .count_active()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day17,
+        example_1: common::example_input!("1") => { part1: 112, part2: 848 },
+    }
+}
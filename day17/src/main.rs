@@ -1,33 +1,35 @@
-mod cube;
-
-use std::fs;
-
-use cube::{Collection, Position3D, Position4D, Position5D};
+use common::bench::{Phase, PhaseTimings};
+use common::report::{Part, PartResult};
+use day17::Day17;
 
 fn main() -> anyhow::Result<()> {
     let options = common::simple_cli::Opts::get();
 
-    let content = fs::read_to_string(options.input)?;
-
-    // Part 1
-    let result_1 = Collection::<Position3D>::from(&content).multi_step(6);
-    println!(
-        "Active 3D cubes after 6th cycle: {}",
-        result_1.count_active()
-    );
-
-    // Part 2
-    let result_2 = Collection::<Position4D>::from(&content).multi_step(6);
-    println!(
-        "Active 4D cubes after 6th cycle: {}",
-        result_2.count_active()
-    );
-
-    let result_5d = Collection::<Position5D>::from(&content).multi_step(6);
-    println!(
-        "Active 5D cubes after 6th cycle: {}",
-        result_5d.count_active()
-    );
+    let content = common::input::load(&options.source_for::<Day17>()?)?;
+    let input = day17::parse(&content)?;
+
+    if let Some(runs) = options.bench {
+        let bench = common::bench::bench::<Day17>(&content, runs)?.with_phase(
+            PhaseTimings::measure(Phase::Part(Part::Extra("5d".to_owned())), runs, || {
+                day17::extra_5d(&input)
+            }),
+        );
+        options.format.print_bench(&[bench])?;
+        return Ok(());
+    }
+
+    let report = common::solution::solve::<Day17>(&content)?
+        .with_label(Part::One, "Active 3D cubes after 6th cycle")
+        .with_label(Part::Two, "Active 4D cubes after 6th cycle")
+        .with_part(PartResult::timed(Part::Extra("5d".to_owned()), || {
+            day17::extra_5d(&input)
+        }))
+        .with_label(
+            Part::Extra("5d".to_owned()),
+            "Active 5D cubes after 6th cycle",
+        );
+
+    options.format.print(&[report])?;
 
     Ok(())
 }